@@ -3,59 +3,60 @@ use chrono::{DateTime, Utc};
 
 use date_format::*;
 use error::HelpScoutError;
-use client::Client;
+use client::{Client, FieldError};
+use id::{MailboxId, FolderId, ConversationId, ThreadId, CustomerId, UserId, AttachmentId};
+use validate::Problems;
 use envelope::{Collection, Item};
 use api::person::Person;
 use api::mailboxes::MailboxRef;
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationType {
-    Email,
-    Chat,
-    Phone,
+api_enum! {
+    pub enum ConversationType {
+        Email => "email",
+        Chat => "chat",
+        Phone => "phone",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationStatus {
-    Active,
-    Pending,
-    Closed,
-    Spam,
+api_enum! {
+    pub enum ConversationStatus {
+        Active => "active",
+        Pending => "pending",
+        Closed => "closed",
+        Spam => "spam",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationSourceType {
-    Email,
-    Web,
-    Notification,
-    #[serde(rename = "emailfwd")]
-    EmailForward,
-    Api,
-    Chat,
-    Workflows,
+api_enum! {
+    pub enum ConversationSourceType {
+        Email => "email",
+        Web => "web",
+        Notification => "notification",
+        EmailForward => "emailfwd",
+        Api => "api",
+        Chat => "chat",
+        Workflows => "workflows",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationSourceVia {
-    Customer,
-    User,
+api_enum! {
+    pub enum ConversationSourceVia {
+        Customer => "customer",
+        User => "user",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationThreadType {
-    LineItem,
-    Note,
-    Message,
-    Chat,
-    Customer,
-    ForwardParent,
-    ForwardChild,
-    Phone,
+api_enum! {
+    pub enum ConversationThreadType {
+        LineItem => "lineitem",
+        Note => "note",
+        Message => "message",
+        Chat => "chat",
+        Customer => "customer",
+        ForwardParent => "forwardparent",
+        ForwardChild => "forwardchild",
+        Phone => "phone",
+    }
 }
 
 // Implemented to satisfy Default, which is customer in this case.
@@ -63,46 +64,46 @@ impl Default for ConversationThreadType {
     fn default() -> ConversationThreadType { ConversationThreadType::Customer }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationThreadStatus {
-    NoChange,
-    Active,
-    Pending,
-    Closed,
-    Spam,
+api_enum! {
+    pub enum ConversationThreadStatus {
+        NoChange => "nochange",
+        Active => "active",
+        Pending => "pending",
+        Closed => "closed",
+        Spam => "spam",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub enum ConversationActionType {
-    MovedFromMailbox,
-    Merged,
-    Imported,
-    Workflow,
-    ImportedExternal,
-    ChangedTicketCustomer,
-    DeletedTicket,
-    RestoreTicket,
-    OriginalCreator,
+api_enum! {
+    pub enum ConversationActionType {
+        MovedFromMailbox => "movedFromMailbox",
+        Merged => "merged",
+        Imported => "imported",
+        Workflow => "workflow",
+        ImportedExternal => "importedExternal",
+        ChangedTicketCustomer => "changedTicketCustomer",
+        DeletedTicket => "deletedTicket",
+        RestoreTicket => "restoreTicket",
+        OriginalCreator => "originalCreator",
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationThreadState {
-    Published,
-    Draft,
-    UnderReview,
-    Hidden,
+api_enum! {
+    pub enum ConversationThreadState {
+        Published => "published",
+        Draft => "draft",
+        UnderReview => "underreview",
+        Hidden => "hidden",
+    }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Conversation {
-    pub id: i32,
+    pub id: ConversationId,
     #[serde(rename = "type")]
     pub conversation_type: ConversationType,
-    pub folder_id: i32,
+    pub folder_id: FolderId,
     pub is_draft: bool,
     pub number: i32,
     pub owner: Option<Person>,
@@ -158,12 +159,42 @@ impl NewConversation {
             threads: threads,
         }
     }
+
+    /// Check the conversation against HelpScout's documented constraints.
+    ///
+    /// This runs automatically in `create` unless `Client::validate_requests`
+    /// is turned off.
+    pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let mut problems = Problems::new();
+
+        if self.customer.id <= 0 && self.customer.email.as_ref().map_or(true, |e| e.trim().is_empty()) {
+            problems.push("customer", "needs an id or email", None);
+        }
+        if let Some(ref email) = self.customer.email {
+            problems.email("customer.email", email);
+        }
+        if self.mailbox.id.0 <= 0 {
+            problems.push("mailbox.id", "is required", None);
+        }
+        problems.required("subject", &self.subject, 998);
+
+        if self.threads.is_empty() {
+            problems.push("threads", "at least one thread is required", None);
+        }
+        for (i, thread) in self.threads.iter().enumerate() {
+            if thread.body.trim().is_empty() {
+                problems.push(&format!("threads[{}].body", i), "is required", None);
+            }
+        }
+
+        problems.into_result()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Attachment {
-    pub id: i32,
+    pub id: AttachmentId,
     pub hash: String,
     pub mime_type: String,
     pub file_name: String,
@@ -175,7 +206,7 @@ pub struct Attachment {
 
 #[derive(Debug, Deserialize)]
 pub struct AttachmentData {
-    pub id: i32,
+    pub id: AttachmentId,
 
     // base64 encoded data
     pub data: String,
@@ -191,7 +222,7 @@ pub struct ConversationSource {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationThread {
-    pub id: i32,
+    pub id: ThreadId,
     #[serde(rename = "type")]
     pub conversation_thread_type: ConversationThreadType,
     pub assigned_to: Option<Person>,
@@ -225,7 +256,7 @@ pub struct NewConversationThread {
     pub created_at: Option<DateTime<Utc>>,
     pub cc: Option<Vec<String>>,
     pub bcc: Option<Vec<String>>,
-    pub attachments: Option<Vec<Attachment>>,
+    pub attachments: Option<Vec<AttachmentHash>>,
 }
 
 impl NewConversationThread {
@@ -290,31 +321,31 @@ impl ConversationListParamBuilder {
         self
     }
 }
-pub fn list(client: &Client, mailbox_id: i32, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
+pub fn list(client: &Client, mailbox_id: MailboxId, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
     let res = client.get(&format!("mailboxes/{}/conversations.json", mailbox_id), params)?;
-    let conversations = serde_json::from_value(res.clone())?;
+    let conversations = Collection::from_response(client, res)?;
     Ok(conversations)
 }
 
-pub fn list_by_folder(client: &Client, mailbox_id: i32, folder_id: i32, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
+pub fn list_by_folder(client: &Client, mailbox_id: MailboxId, folder_id: FolderId, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
     let res = client.get(&format!("mailboxes/{}/folders/{}/conversations.json", mailbox_id, folder_id), params)?;
-    let conversations = serde_json::from_value(res.clone())?;
+    let conversations = Collection::from_response(client, res)?;
     Ok(conversations)
 }
 
-pub fn list_by_customer(client: &Client, mailbox_id: i32, customer_id: i32, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
+pub fn list_by_customer(client: &Client, mailbox_id: MailboxId, customer_id: CustomerId, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
     let res = client.get(&format!("mailboxes/{}/customers/{}/conversations.json", mailbox_id, customer_id), params)?;
-    let conversations = serde_json::from_value(res.clone())?;
+    let conversations = Collection::from_response(client, res)?;
     Ok(conversations)
 }
 
-pub fn list_by_user(client: &Client, mailbox_id: i32, user_id: i32, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
+pub fn list_by_user(client: &Client, mailbox_id: MailboxId, user_id: UserId, params: &mut ConversationListParamBuilder) -> Result<Collection<Conversation>, HelpScoutError> {
     let res = client.get(&format!("mailboxes/{}/users/{}/conversations.json", mailbox_id, user_id), params)?;
-    let conversations = serde_json::from_value(res.clone())?;
+    let conversations = Collection::from_response(client, res)?;
     Ok(conversations)
 }
 
-pub fn get(client: &Client, id: i32) -> Result<Item<Conversation>, HelpScoutError> {
+pub fn get(client: &Client, id: ConversationId) -> Result<Item<Conversation>, HelpScoutError> {
     let res = client.get(&format!("conversations/{}.json", id), ())?;
     let conversation = serde_json::from_value(res.clone())?;
     Ok(conversation)
@@ -323,6 +354,7 @@ pub fn get(client: &Client, id: i32) -> Result<Item<Conversation>, HelpScoutErro
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationCreateParamBuilder {
+    #[serde(skip)]
     pub(crate) conversation: NewConversation,
     pub(crate) imported: Option<bool>,
     pub(crate) auto_reply: Option<bool>,
@@ -353,28 +385,66 @@ impl ConversationCreateParamBuilder {
         self.auto_reply = Some(auto_reply);
         self
     }
+
+    /// Create the conversation, passing `imported`, `autoReply` and `reload`
+    /// along as query parameters.
+    pub fn send(&self, client: &Client) -> Result<(), HelpScoutError> {
+        if client.validate_requests {
+            self.conversation.validate().map_err(HelpScoutError::ValidationError)?;
+        }
+        let body = serde_json::to_value(&self.conversation)?;
+        client.post("conversations.json", self, Some(body.to_string()))?;
+        Ok(())
+    }
 }
 
 pub fn create(client: &Client, conversation: &NewConversation) -> Result<(), HelpScoutError> {
+    if client.validate_requests {
+        conversation.validate().map_err(HelpScoutError::ValidationError)?;
+    }
     let body = serde_json::to_value(conversation)?;
     client.post("conversations.json", (), Some(body.to_string()))?;
     Ok(())
 }
 
 /* TODO: Flesh out update conversation from Conversation object
-pub fn update(client: &Client, id: i32) -> Result<Item<Conversation>, HelpScoutError> {
+pub fn update(client: &Client, id: ConversationId) -> Result<Item<Conversation>, HelpScoutError> {
     let res = client.get(&format!("conversations/{}.json", id), None)?;
     let conversation = serde_json::from_value(res.clone())?;
     Ok(conversation)
 }
 */
-pub fn delete(client: &Client, id: i32) -> Result<Item<Conversation>, HelpScoutError> {
+pub fn delete(client: &Client, id: ConversationId) -> Result<Item<Conversation>, HelpScoutError> {
     let res = client.get(&format!("conversations/{}.json", id), ())?;
     let conversation = serde_json::from_value(res.clone())?;
     Ok(conversation)
 }
 
-pub fn get_attachment_data(client: &Client, id: i32) -> Result<Item<AttachmentData>, HelpScoutError> {
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewAttachment {
+    pub file_name: String,
+    pub mime_type: String,
+
+    // base64 encoded data
+    pub data: String,
+}
+
+/// An uploaded attachment. New threads refer to their attachments by hash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentHash {
+    pub hash: String,
+}
+
+/// Upload an attachment, to be added to a thread by its hash.
+pub fn upload_attachment(client: &Client, attachment: &NewAttachment) -> Result<Item<AttachmentHash>, HelpScoutError> {
+    let body = serde_json::to_value(attachment)?;
+    let res = client.post("attachments.json", (), Some(body.to_string()))?;
+    let hash = serde_json::from_value(res)?;
+    Ok(hash)
+}
+
+pub fn get_attachment_data(client: &Client, id: AttachmentId) -> Result<Item<AttachmentData>, HelpScoutError> {
     let res = client.get(&format!("attachments/{}/data.json", id), ())?;
     let attachment_data = serde_json::from_value(res.clone())?;
     Ok(attachment_data)
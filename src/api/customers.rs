@@ -4,33 +4,35 @@ use chrono::{DateTime, Utc};
 use date_format::*;
 
 use error::HelpScoutError;
-use client::Client;
+use client::{Client, FieldError};
 use envelope::{Collection, Item};
-
-
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CustomerSocialProfileType {
-    Twitter,
-    Facebook,
-    Linkedin,
-    Aboutme,
-    Google,
-    Googleplus,
-    Tungleme,
-    Quora,
-    Foursquare,
-    Youtube,
-    Flickr,
-    Other,
+use id::{MailboxId, CustomerId};
+use validate::Problems;
+
+
+api_enum! {
+    pub enum CustomerSocialProfileType {
+        Twitter => "twitter",
+        Facebook => "facebook",
+        Linkedin => "linkedin",
+        Aboutme => "aboutme",
+        Google => "google",
+        Googleplus => "googleplus",
+        Tungleme => "tungleme",
+        Quora => "quora",
+        Foursquare => "foursquare",
+        Youtube => "youtube",
+        Flickr => "flickr",
+        Other => "other",
+    }
 }
 
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CustomerEmailLocationType {
-    Home,
-    Work,
-    Other,
+api_enum! {
+    pub enum CustomerEmailLocationType {
+        Home => "home",
+        Work => "work",
+        Other => "other",
+    }
 }
 
 impl Default for CustomerEmailLocationType {
@@ -39,55 +41,55 @@ impl Default for CustomerEmailLocationType {
     }
 }
 
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CustomerChatType {
-    Aim,
-    Gtalk,
-    Icq,
-    Xmpp,
-    Msn,
-    Skype,
-    Yahoo,
-    Qq,
-    Other,
+api_enum! {
+    pub enum CustomerChatType {
+        Aim => "aim",
+        Gtalk => "gtalk",
+        Icq => "icq",
+        Xmpp => "xmpp",
+        Msn => "msn",
+        Skype => "skype",
+        Yahoo => "yahoo",
+        Qq => "qq",
+        Other => "other",
+    }
 }
 
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CustomerPhoneLocationType {
-    Home,
-    Work,
-    Mobile,
-    Fax,
-    Pager,
-    Other,
+api_enum! {
+    pub enum CustomerPhoneLocationType {
+        Home => "home",
+        Work => "work",
+        Mobile => "mobile",
+        Fax => "fax",
+        Pager => "pager",
+        Other => "other",
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CustomerPhotoType {
-    Unknown,
-    Gravatar,
-    Twitter,
-    Facebook,
-    Googleprofile,
-    Googleplus,
-    Linkedin,
+api_enum! {
+    pub enum CustomerPhotoType {
+        NotSpecified => "unknown",
+        Gravatar => "gravatar",
+        Twitter => "twitter",
+        Facebook => "facebook",
+        Googleprofile => "googleprofile",
+        Googleplus => "googleplus",
+        Linkedin => "linkedin",
+    }
 }
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CustomerGender {
-    Male,
-    Female,
-    Unknown,
+api_enum! {
+    pub enum CustomerGender {
+        Male => "male",
+        Female => "female",
+        NotSpecified => "unknown",
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Customer {
-    pub id: i32,
+    pub id: CustomerId,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub full_name: Option<String>,
@@ -310,7 +312,7 @@ impl CustomersListParamBuilder {
     /// ```
     pub fn list(&self, client: &Client) -> Result<Collection<Customer>, HelpScoutError> {
         let res = client.get("customers.json", &self)?;
-        let customers = serde_json::from_value(res.clone())?;
+        let customers = Collection::from_response(client, res)?;
         Ok(customers)
     }
 
@@ -335,15 +337,15 @@ impl CustomersListParamBuilder {
     ///     let client = helpscout::Client::example();
     ///
     ///     //Grab list of mailboxes under an account to provide a mailbox ID for testing.
-    ///     let mailboxes = mailboxes::list(&client).expect("Mailboxes to be listed");
+    ///     let mailboxes = mailboxes::list(&client, None).expect("Mailboxes to be listed");
     ///
     ///     //Return list of customers under the specified mailbox.
     ///     helpscout::api::customers().list_by_mailbox(&client, mailboxes.items[0].id)
     /// }
     /// ```
-    pub fn list_by_mailbox(&self, client: &Client, mailbox_id: i32) -> Result<Collection<Customer>, HelpScoutError> {
+    pub fn list_by_mailbox(&self, client: &Client, mailbox_id: MailboxId) -> Result<Collection<Customer>, HelpScoutError> {
         let res = client.get(&format!("mailboxes/{}/customers.json", mailbox_id), &self)?;
-        let customers = serde_json::from_value(res.clone())?;
+        let customers = Collection::from_response(client, res)?;
         Ok(customers)
     }
 }
@@ -361,7 +363,7 @@ impl CustomersListParamBuilder {
 ///
 /// fn main() {
 ///     let customer = get_customer().expect("the first customer in the list be returned with all optional params");
-///     assert!(customer.item.id > 0);
+///     assert!(customer.item.id.0 > 0);
 /// }
 ///
 /// fn get_customer() -> Result<Item<Customer>, HelpScoutError> {
@@ -375,7 +377,7 @@ impl CustomersListParamBuilder {
 ///     customers::get(&client, customers.items[0].id)
 /// }
 /// ```
-pub fn get(client: &Client, id: i32) -> Result<Item<Customer>, HelpScoutError> {
+pub fn get(client: &Client, id: CustomerId) -> Result<Item<Customer>, HelpScoutError> {
     let res = client.get(&format!("customers/{}.json", id), ())?;
     let customer = serde_json::from_value(res.clone())?;
     Ok(customer)
@@ -546,7 +548,71 @@ impl NewCustomer {
         self
     }
 
+    /// Check the customer against HelpScout's documented constraints.
+    ///
+    /// This runs automatically in `send` unless `Client::validate_requests`
+    /// is turned off.
+    pub fn validate(&self) -> Result<(), Vec<FieldError>> {
+        let mut problems = Problems::new();
+
+        if self.first_name.trim().is_empty() && self.last_name.trim().is_empty() && self.emails.is_empty() {
+            problems.push("customer", "needs a first name, last name or email", None);
+        }
+        problems.max_len("firstName", &self.first_name, 40);
+        problems.max_len("lastName", &self.last_name, 40);
+        problems.optional_max_len("organization", &self.organization, 60);
+        problems.optional_max_len("jobTitle", &self.job_title, 60);
+        problems.optional_max_len("background", &self.background, 200);
+
+        for (i, email) in self.emails.iter().enumerate() {
+            let path = format!("emails[{}].value", i);
+            problems.email(&path, &email.value);
+            problems.max_len(&path, &email.value, 100);
+        }
+
+        if let Some(ref address) = self.address {
+            problems.required("address.city", &address.city, 60);
+            problems.required("address.state", &address.state, 60);
+            problems.required("address.postalCode", &address.postal_code, 10);
+            problems.required("address.country", &address.country, 2);
+            for (i, line) in address.lines.iter().enumerate() {
+                problems.max_len(&format!("address.lines[{}]", i), line, 100);
+            }
+        }
+
+        if let Some(ref profiles) = self.social_profiles {
+            for (i, profile) in profiles.iter().enumerate() {
+                problems.required(&format!("socialProfiles[{}].value", i), &profile.value, 200);
+            }
+        }
+
+        if let Some(ref phones) = self.phones {
+            for (i, phone) in phones.iter().enumerate() {
+                problems.required(&format!("phones[{}].value", i), &phone.value, 40);
+            }
+        }
+
+        if let Some(ref chats) = self.chats {
+            for (i, chat) in chats.iter().enumerate() {
+                problems.required(&format!("chats[{}].value", i), &chat.value, 100);
+            }
+        }
+
+        if let Some(ref websites) = self.websites {
+            for (i, website) in websites.iter().enumerate() {
+                let path = format!("websites[{}].value", i);
+                problems.url(&path, &website.value);
+                problems.max_len(&path, &website.value, 200);
+            }
+        }
+
+        problems.into_result()
+    }
+
     pub fn send(&self, client: &Client) -> Result<(), HelpScoutError> {
+        if client.validate_requests {
+            self.validate().map_err(HelpScoutError::ValidationError)?;
+        }
         let body = serde_json::to_value(self)?;
         //println!("{:?}", body);
         client.post("customers.json", (), Some(body.to_string()))?;
@@ -642,7 +708,7 @@ impl UpdatedCustomer {
         self
     }
 
-    pub fn send(&self, client: &Client, id: i32) -> Result<(), HelpScoutError> {
+    pub fn send(&self, client: &Client, id: CustomerId) -> Result<(), HelpScoutError> {
         let body = serde_json::to_value(self)?;
         client.put(
             &format!("customers/{}.json", id),
@@ -4,12 +4,13 @@ use chrono::{DateTime, Utc};
 
 use error::HelpScoutError;
 use client::Client;
-use envelope::{Collection, Item};
+use envelope::{Collection, Item, Page};
+use id::{MailboxId, FolderId, UserId};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Mailbox {
-    pub id: i32,
+    pub id: MailboxId,
     pub name: String,
     pub slug: String,
     pub email: String,
@@ -27,18 +28,18 @@ pub struct Mailbox {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MailboxRef {
-    pub id: i32,
+    pub id: MailboxId,
     pub name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum CustomFieldType {
-    SingleLine,
-    MultiLine,
-    Data,
-    Number,
-    Dropdown,
+api_enum! {
+    pub enum CustomFieldType {
+        SingleLine => "SINGLE_LINE",
+        MultiLine => "MULTI_LINE",
+        Data => "DATA",
+        Number => "NUMBER",
+        Dropdown => "DROPDOWN",
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -54,11 +55,11 @@ pub struct CustomField {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Folder {
-    pub id: i32,
+    pub id: FolderId,
     pub name: String,
     #[serde(rename = "type")]
     pub folder_type: String,
-    pub user_id: i32,
+    pub user_id: UserId,
     pub total_count: i32,
     pub active_count: i32,
     pub modified_at: DateTime<Utc>,
@@ -69,6 +70,8 @@ pub struct Folder {
 /// API docs:
 /// <https://developer.helpscout.com/help-desk-api/mailboxes/list/>
 ///
+/// `page` defaults to the first.
+///
 /// ```rust
 /// extern crate helpscout;
 ///
@@ -82,12 +85,12 @@ pub struct Folder {
 ///
 /// fn list_mailboxes() -> Result<Collection<Mailbox>, HelpScoutError> {
 ///     let client = helpscout::Client::example();
-///     mailboxes::list(&client)
+///     mailboxes::list(&client, None)
 /// }
 /// ```
-pub fn list(client: &Client) -> Result<Collection<Mailbox>, HelpScoutError> {
-    let res = client.get("mailboxes.json", ())?;
-    let mailboxes = serde_json::from_value(res.clone())?;
+pub fn list(client: &Client, page: Option<i32>) -> Result<Collection<Mailbox>, HelpScoutError> {
+    let res = client.get("mailboxes.json", Page { page: page })?;
+    let mailboxes = Collection::from_response(client, res)?;
     Ok(mailboxes)
 }
 
@@ -104,7 +107,7 @@ pub fn list(client: &Client) -> Result<Collection<Mailbox>, HelpScoutError> {
 ///
 /// fn main() {
 ///     let mailbox = get_mailbox().expect("get mailbox");
-///     assert!(mailbox.item.id > 0);
+///     assert!(mailbox.item.id.0 > 0);
 /// }
 ///
 /// fn get_mailbox() -> Result<Item<Mailbox>, HelpScoutError> {
@@ -113,11 +116,11 @@ pub fn list(client: &Client) -> Result<Collection<Mailbox>, HelpScoutError> {
 ///     // Grab the list of mailboxes to fetch ids because
 ///     // we don't already know the ID ahead of time. You
 ///     // may already have the ID handy.
-///     let mailboxes = mailboxes::list(&client)?;
+///     let mailboxes = mailboxes::list(&client, None)?;
 ///     mailboxes::get(&client, mailboxes.items[0].id)
 /// }
 /// ```
-pub fn get(client: &Client, id: i32) -> Result<Item<Mailbox>, HelpScoutError> {
+pub fn get(client: &Client, id: MailboxId) -> Result<Item<Mailbox>, HelpScoutError> {
     let res = client.get(&format!("mailboxes/{}.json", id), ())?;
     let mailbox = serde_json::from_value(res.clone())?;
     Ok(mailbox)
@@ -128,6 +131,8 @@ pub fn get(client: &Client, id: i32) -> Result<Item<Mailbox>, HelpScoutError> {
 /// API docs:
 /// <https://developer.helpscout.com/help-desk-api/mailboxes/folders/>
 ///
+/// `page` defaults to the first.
+///
 /// ```rust
 /// extern crate helpscout;
 ///
@@ -141,12 +146,12 @@ pub fn get(client: &Client, id: i32) -> Result<Item<Mailbox>, HelpScoutError> {
 ///
 /// fn get_folders() -> Result<Collection<Folder>, HelpScoutError> {
 ///     let client = helpscout::Client::example();
-///     let mailboxes = mailboxes::list(&client)?;
-///     mailboxes::get_folders(&client, mailboxes.items[0].id)
+///     let mailboxes = mailboxes::list(&client, None)?;
+///     mailboxes::get_folders(&client, mailboxes.items[0].id, None)
 /// }
 /// ```
-pub fn get_folders(client: &Client, mailbox_id:i32) -> Result<Collection<Folder>, HelpScoutError>{
-    let res = client.get(&format!("mailboxes/{}/folders.json", mailbox_id), ())?;
-    let folders = serde_json::from_value(res.clone())?;
+pub fn get_folders(client: &Client, mailbox_id: MailboxId, page: Option<i32>) -> Result<Collection<Folder>, HelpScoutError>{
+    let res = client.get(&format!("mailboxes/{}/folders.json", mailbox_id), Page { page: page })?;
+    let folders = Collection::from_response(client, res)?;
     Ok(folders)
 }
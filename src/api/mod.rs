@@ -7,7 +7,7 @@ pub mod reports;
 pub mod teams;
 pub mod tags;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone};
 
 use self::users::UsersBuilder;
 use self::customers::CustomersListParamBuilder;
@@ -26,6 +26,6 @@ pub fn customers() -> CustomersListParamBuilder {
     CustomersListParamBuilder::default()
 }
 
-pub fn report(start: DateTime<Utc>, end: DateTime<Utc>) -> ReportBuilder {
+pub fn report<Tz: TimeZone>(start: DateTime<Tz>, end: DateTime<Tz>) -> ReportBuilder {
     ReportBuilder::new(start, end)
 }
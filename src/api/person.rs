@@ -1,15 +1,16 @@
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum PersonType {
-    User,
-    Customer,
-    Team
+api_enum! {
+    pub enum PersonType {
+        User => "user",
+        Customer => "customer",
+        Team => "team",
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Person {
-    pub id: i32,
+    // Users, customers and teams all appear as people, so this is left untyped.
+    pub id: i64,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub email: Option<String>,
@@ -22,9 +23,9 @@ pub struct Person {
 }
 
 impl Person {
-    pub fn new(id: i32) -> Person {
+    pub fn new<I: Into<i64>>(id: I) -> Person {
         Person {
-            id: id,
+            id: id.into(),
             first_name: None,
             last_name: None,
             email: None,
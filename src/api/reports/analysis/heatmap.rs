@@ -0,0 +1,187 @@
+//! Busy Times Heatmap
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::api::report;
+//! use helpscout::api::reports::analysis::heatmap::Heatmap;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::weeks(4);
+//!     let end = Utc::now();
+//!     let busy = report(start, end)
+//!         .conversations()
+//!         .busy_times(&client)
+//!         .expect("run busy times report");
+//!
+//!     // Average conversations per hour of a typical week
+//!     let heatmap = Heatmap::from_stats(&busy).per_week(start, end);
+//!     println!("{}", heatmap.to_ascii());
+//!
+//!     for window in heatmap.staffing_windows(4, 3) {
+//!         println!("{:?} {}:00-{}:00 ({:.1})", window.day, window.start_hour, window.end_hour, window.load);
+//!     }
+//! }
+//! ```
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc, Weekday};
+
+use api::reports::conversations::BusyTimeStatistics;
+
+const DAYS: [Weekday; 7] = [
+    Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu,
+    Weekday::Fri, Weekday::Sat, Weekday::Sun,
+];
+
+// Lightest to darkest
+const SHADES: &'static [char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// Conversation counts for each hour of the week, Monday first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap {
+    cells: [[f64; 24]; 7],
+}
+
+/// A run of hours worth having extra people around for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaffingWindow {
+    pub day: Weekday,
+    pub start_hour: u32,
+    /// Exclusive
+    pub end_hour: u32,
+    /// Combined count across the window
+    pub load: f64,
+}
+
+impl Heatmap {
+    /// Pivot the busy times report. HelpScout numbers days 1 (Monday) to
+    /// 7 (Sunday); anything outside that or 0-23 hours is ignored.
+    pub fn from_stats(stats: &[BusyTimeStatistics]) -> Heatmap {
+        let mut cells = [[0.0; 24]; 7];
+        for stat in stats {
+            if stat.day >= 1 && stat.day <= 7 && stat.hour >= 0 && stat.hour < 24 {
+                cells[(stat.day - 1) as usize][stat.hour as usize] += stat.count as f64;
+            }
+        }
+        Heatmap { cells: cells }
+    }
+
+    /// The count for an hour of a day, or `None` if `hour` isn't 0-23.
+    pub fn get(&self, day: Weekday, hour: u32) -> Option<f64> {
+        self.cells[day.num_days_from_monday() as usize].get(hour as usize).cloned()
+    }
+
+    /// Divide by the number of weeks between `start` and `end`, giving the
+    /// count for an average week. Ranges under a week are left as is.
+    pub fn per_week(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Heatmap {
+        let weeks = end.signed_duration_since(start).num_seconds() as f64 / (7.0 * 24.0 * 3600.0);
+        let weeks = if weeks > 1.0 { weeks } else { 1.0 };
+
+        let mut cells = self.cells;
+        for row in cells.iter_mut() {
+            for cell in row.iter_mut() {
+                *cell /= weeks;
+            }
+        }
+        Heatmap { cells: cells }
+    }
+
+    pub fn max(&self) -> f64 {
+        self.cells.iter().flat_map(|row| row.iter()).fold(0.0, |max, &c| if c > max { c } else { max })
+    }
+
+    /// A day per line and a character per hour, darker for busier.
+    pub fn to_ascii(&self) -> String {
+        let max = self.max();
+        let mut out = String::from("    ");
+        for hour in 0..24 {
+            out.push_str(&(hour % 10).to_string());
+        }
+        out.push('\n');
+
+        for (i, row) in self.cells.iter().enumerate() {
+            let _ = write!(out, "{:?} ", DAYS[i]);
+            for &cell in row.iter() {
+                out.push(SHADES[shade(cell, max, SHADES.len())]);
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// A standalone SVG with `cell` pixel squares, hours across and days
+    /// down.
+    pub fn to_svg(&self, cell: u32) -> String {
+        let max = self.max();
+        let label = cell * 2;
+        let width = label + cell * 24;
+        let height = cell * 7;
+
+        let mut out = String::new();
+        let _ = writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-size="{}">"#, width, height, cell * 2 / 3);
+        for (d, row) in self.cells.iter().enumerate() {
+            let y = d as u32 * cell;
+            let _ = writeln!(out, r#"  <text x="0" y="{}">{:?}</text>"#, y + cell * 3 / 4, DAYS[d]);
+            for (h, &count) in row.iter().enumerate() {
+                let opacity = if max > 0.0 { count / max } else { 0.0 };
+                let _ = writeln!(out, r##"  <rect x="{}" y="{}" width="{}" height="{}" fill="#1f8dd6" fill-opacity="{:.3}"><title>{:?} {}:00 {:.1}</title></rect>"##,
+                                 label + h as u32 * cell, y, cell, cell, opacity, DAYS[d], h, count);
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// The `count` busiest runs of `hours` consecutive hours, busiest first.
+    /// Windows stay within a day and don't overlap each other.
+    pub fn staffing_windows(&self, hours: u32, count: usize) -> Vec<StaffingWindow> {
+        if hours == 0 || hours > 24 {
+            return Vec::new();
+        }
+
+        let mut candidates = Vec::new();
+        for (d, row) in self.cells.iter().enumerate() {
+            for start in 0..(24 - hours + 1) {
+                let load = row[start as usize..(start + hours) as usize].iter().sum();
+                candidates.push(StaffingWindow {
+                    day: DAYS[d],
+                    start_hour: start,
+                    end_hour: start + hours,
+                    load: load,
+                });
+            }
+        }
+        candidates.sort_by(|a, b| b.load.partial_cmp(&a.load).unwrap_or(::std::cmp::Ordering::Equal));
+
+        let mut picked: Vec<StaffingWindow> = Vec::new();
+        for candidate in candidates {
+            if picked.len() == count || candidate.load <= 0.0 {
+                break;
+            }
+            let overlaps = picked.iter().any(|p| {
+                p.day == candidate.day && p.start_hour < candidate.end_hour && candidate.start_hour < p.end_hour
+            });
+            if !overlaps {
+                picked.push(candidate);
+            }
+        }
+        picked
+    }
+}
+
+fn shade(value: f64, max: f64, levels: usize) -> usize {
+    if max <= 0.0 || value <= 0.0 {
+        return 0;
+    }
+    // Anything above zero gets at least the lightest mark
+    let level = (value / max * (levels - 1) as f64).ceil() as usize;
+    if level >= levels { levels - 1 } else { level }
+}
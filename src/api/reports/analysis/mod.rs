@@ -0,0 +1,11 @@
+//! Report Analysis
+//!
+//! Helpers for making sense of report results once they've been fetched.
+//! Nothing here talks to HelpScout.
+//!
+//! - [Heatmap](heatmap/index.html): pivot busy times into a week grid,
+//!   render it and suggest when to staff up
+//! - [Series](series/index.html): resample and smooth the new conversations
+//!   and received messages time series
+pub mod heatmap;
+pub mod series;
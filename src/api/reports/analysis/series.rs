@@ -0,0 +1,107 @@
+//! Time Series
+//!
+//! Turns the new conversations and received messages reports into plain
+//! `(date, value)` points that can be regrouped and smoothed. Grouping is
+//! done on UTC dates, with weeks starting on Monday.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::analysis::series::{self, moving_average, resample};
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let received = report(Utc::now() - Duration::days(90), Utc::now())
+//!         .conversations()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .received_messages(&client)
+//!         .expect("run received messages report");
+//!
+//!     let daily = series::points(&received.current);
+//!     let weekly = resample(&daily, &ConvReportViewByType::Week);
+//!     let trend = moving_average(&daily, 7);
+//!     println!("{:?}\n{:?}", weekly, trend);
+//! }
+//! ```
+use chrono::{Datelike, DateTime, Duration, NaiveDate, TimeZone, Utc};
+
+use api::reports::conversations::{ConvReportViewByType, NewConversationsStatistics, ReceivedMessagesStatistics};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub date: DateTime<Utc>,
+    pub value: f64,
+}
+
+/// Report entries that are one point in a time series.
+pub trait ToPoint {
+    fn to_point(&self) -> Point;
+}
+
+impl ToPoint for NewConversationsStatistics {
+    fn to_point(&self) -> Point {
+        Point { date: self.start, value: self.count as f64 }
+    }
+}
+
+impl ToPoint for ReceivedMessagesStatistics {
+    fn to_point(&self) -> Point {
+        Point { date: self.date, value: self.messages as f64 }
+    }
+}
+
+pub fn points<T: ToPoint>(stats: &[T]) -> Vec<Point> {
+    stats.iter().map(ToPoint::to_point).collect()
+}
+
+/// Add up points by day, week or month. Each resulting point is dated at
+/// the start of its interval; intervals with no points are left out.
+pub fn resample(points: &[Point], interval: &ConvReportViewByType) -> Vec<Point> {
+    let mut buckets: Vec<Point> = Vec::new();
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.date.cmp(&b.date));
+
+    for point in sorted {
+        let start = interval_start(point.date, interval);
+        let same = buckets.last().map(|last| last.date == start).unwrap_or(false);
+        if same {
+            buckets.last_mut().unwrap().value += point.value;
+        } else {
+            buckets.push(Point { date: start, value: point.value });
+        }
+    }
+    buckets
+}
+
+/// Trailing average over `window` points. The first `window - 1` points
+/// don't have enough history and are skipped.
+pub fn moving_average(points: &[Point], window: usize) -> Vec<Point> {
+    if window == 0 || points.len() < window {
+        return Vec::new();
+    }
+    points.windows(window)
+        .map(|w| Point {
+            date: w[window - 1].date,
+            value: w.iter().map(|p| p.value).sum::<f64>() / window as f64,
+        })
+        .collect()
+}
+
+fn interval_start(date: DateTime<Utc>, interval: &ConvReportViewByType) -> DateTime<Utc> {
+    let day = date.naive_utc().date();
+    let start = match *interval {
+        ConvReportViewByType::Week => day - Duration::days(day.weekday().num_days_from_monday() as i64),
+        ConvReportViewByType::Month => NaiveDate::from_ymd_opt(day.year(), day.month(), 1).unwrap(),
+        // Days, and anything newer we don't know how to group
+        _ => day,
+    };
+    Utc.from_utc_datetime(&start.and_hms_opt(0, 0, 0).unwrap())
+}
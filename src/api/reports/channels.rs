@@ -0,0 +1,135 @@
+//! Filters shared by the [Email](../email/index.html),
+//! [Chat](../chat/index.html) and [Phone](../phone/index.html) reports.
+//!
+//! All three channel reports take the same filters, so they hang off one
+//! builder and can be run side by side to compare channels. The builder
+//! implements [ReportFilters](../trait.ReportFilters.html) but not
+//! [TypeFilter](../trait.TypeFilter.html), since each report picks its own
+//! conversation type:
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::api::report;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let channels = report(Utc::now() - Duration::days(7), Utc::now()).channels();
+//!
+//!     let email = channels.clone().email(&client).expect("run email report");
+//!     let chat = channels.clone().chat(&client).expect("run chat report");
+//!     let phone = channels.phone(&client).expect("run phone report");
+//!     println!("{:?} / {:?} / {:?}", email.current.volume, chat.current.volume, phone.current.volume);
+//! }
+//! ```
+use serde;
+use serde_json;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+use client::Client;
+use error::HelpScoutError;
+use date_format::*;
+use duration_format::*;
+use super::{ReportBuilder, ReportFilters, FilterFields};
+
+#[serde(default)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelReportBuilder {
+    #[serde(with = "date_format")]
+    pub(crate) start: DateTime<Utc>,
+    #[serde(with = "date_format")]
+    pub(crate) end: DateTime<Utc>,
+
+    pub(crate) mailboxes: Option<String>,
+    pub(crate) tags: Option<String>,
+    pub(crate) folders: Option<String>,
+
+    #[serde(with = "optional_date_format")]
+    pub(crate) previous_start: Option<DateTime<Utc>>,
+    #[serde(with = "optional_date_format")]
+    pub(crate) previous_end: Option<DateTime<Utc>>,
+
+    pub(crate) office_hours: Option<i32>,
+}
+
+impl ChannelReportBuilder {
+    /* Set methods */
+    pub fn set_office_hours(mut self, office_hours: bool) -> Self {
+        self.office_hours = Some(match office_hours {
+            true => 1,
+            false => 0,
+        });
+        self
+    }
+
+    pub(crate) fn run<T>(self, client: &Client, path: &str) -> Result<T, HelpScoutError>
+        where T: serde::de::DeserializeOwned
+    {
+        let res = client.get(path, self)?;
+        let report = serde_json::from_value(res.clone())?;
+        Ok(report)
+    }
+}
+
+impl ReportFilters for ChannelReportBuilder {
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a> {
+        FilterFields {
+            mailboxes: &mut self.mailboxes,
+            tags: &mut self.tags,
+            folders: &mut self.folders,
+            previous_start: &mut self.previous_start,
+            previous_end: &mut self.previous_end,
+        }
+    }
+}
+
+impl From<ReportBuilder> for ChannelReportBuilder {
+    fn from(report: ReportBuilder) -> Self {
+        ChannelReportBuilder {
+            start: report.start,
+            end: report.end,
+            mailboxes: None,
+            tags: None,
+            folders: None,
+            previous_start: report.previous_start,
+            previous_end: report.previous_end,
+            office_hours: report.office_hours.map(|o| o as i32),
+        }
+    }
+}
+
+// How much came in through a channel
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelVolume {
+    pub total_conversations: Option<i32>,
+    pub new_conversations: Option<i32>,
+    pub customers: Option<i32>,
+    pub messages_received: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelResponseTimes {
+    #[serde(default, with = "optional_duration_format")]
+    pub first_response_time: Option<Duration>,
+    #[serde(default, with = "optional_duration_format")]
+    pub response_time: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelResolution {
+    pub resolved: Option<i32>,
+    pub closed: Option<i32>,
+    pub resolved_on_first_reply: Option<i32>,
+    pub percent_resolved_on_first_reply: Option<f64>,
+    #[serde(default, with = "optional_duration_format")]
+    pub resolution_time: Option<Duration>,
+}
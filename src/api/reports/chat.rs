@@ -0,0 +1,72 @@
+//! Chat Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/chat/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::chat::ChatReport;
+//!
+//! fn main() {
+//!     let report = chat_report().expect("run chat report");
+//!     println!("{:#?}", report);
+//! }
+//!
+//! fn chat_report() -> Result<ChatReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .channels()
+//!         .chat(&client)
+//! }
+//! ```
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+use client::Client;
+use error::HelpScoutError;
+use duration_format::*;
+use api::reports::FilterTag;
+use api::reports::conversations::BusyTimeStatistics;
+use super::channels::{ChannelReportBuilder, ChannelVolume, ChannelResponseTimes, ChannelResolution};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatReport {
+    pub filter_tags: Option<Vec<FilterTag>>,
+    pub current: ChatTimeRangeStatistics,
+    pub previous: Option<ChatTimeRangeStatistics>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatTimeRangeStatistics {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub volume: ChannelVolume,
+    pub response_times: ChannelResponseTimes,
+    pub resolution: ChannelResolution,
+    /// How long customers waited before a chat was picked up
+    #[serde(default, with = "optional_duration_format")]
+    pub wait_time: Option<Duration>,
+    #[serde(default, with = "optional_duration_format")]
+    pub chat_duration: Option<Duration>,
+    pub missed_chats: Option<i32>,
+    #[serde(default)]
+    pub busiest_times: Vec<BusyTimeStatistics>,
+}
+
+impl ChannelReportBuilder {
+    pub fn chat(self, client: &Client) -> Result<ChatReport, HelpScoutError> {
+        self.run(client, "reports/chat.json")
+    }
+}
@@ -0,0 +1,78 @@
+//! Company Customers Helped Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/company/customers-helped/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::company::customers_helped::CustomersHelpedReport;
+//!
+//! fn main() {
+//!     let report = customers_helped_report().expect("run customers helped report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.len() > 0);
+//! }
+//!
+//! fn customers_helped_report() -> Result<CustomersHelpedReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .company()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .customers_helped(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! CustomersHelpedReport {
+//!     current: [
+//!         CustomersHelpedStatistics {
+//!             date: 2018-01-25T00:00:00Z,
+//!             customers: 14
+//!         },
+//!         // More
+//!     ],
+//!     previous: None
+//! }
+//! ```
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use super::CompanyReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomersHelpedReport {
+    pub current: Vec<CustomersHelpedStatistics>,
+    pub previous: Option<Vec<CustomersHelpedStatistics>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomersHelpedStatistics {
+    pub date: DateTime<Utc>,
+    pub customers: i32,
+}
+
+impl CompanyReportBuilder {
+    pub fn customers_helped(self, client: &Client) -> Result<CustomersHelpedReport, HelpScoutError> {
+        let res = client.get("reports/company/customers-helped.json", self)?;
+        let customers = serde_json::from_value(res.clone())?;
+        Ok(customers)
+    }
+}
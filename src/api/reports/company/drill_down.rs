@@ -0,0 +1,53 @@
+//! Company Drill-down Report
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use api::reports::conversations::drill_down::DrillDownConversationsReport;
+use super::CompanyReportBuilder;
+
+impl CompanyReportBuilder {
+/// Company Drilldown
+///
+/// Conversations behind one of the company totals, picked with `range`.
+///
+/// API docs: <https://developer.helpscout.com/help-desk-api/reports/company/drilldown/>
+///
+/// ## Usage
+///
+/// ```rust
+/// extern crate helpscout;
+/// extern crate chrono;
+///
+/// use chrono::prelude::*;
+/// use chrono::Duration;
+///
+/// use helpscout::HelpScoutError;
+/// use helpscout::api::report;
+/// use helpscout::api::reports::company::CompanyDrillDownRange;
+/// use helpscout::api::reports::conversations::drill_down::DrillDownConversationsReport;
+///
+/// fn main() {
+///     let report = drill_down_report().expect("run company drill down report");
+///     println!("{:#?}", report);
+///     assert!(report.conversations.page > 0);
+/// }
+///
+/// fn drill_down_report() -> Result<DrillDownConversationsReport, HelpScoutError> {
+///     let client = helpscout::Client::example();
+///     let start = Utc::now() - Duration::days(7);
+///     let end = Utc::now();
+///     report(start, end)
+///         .company()
+///         .set_range(CompanyDrillDownRange::Resolved)
+///         .set_rows(50)
+///         .drill_down(&client)
+/// }
+/// ```
+///
+    pub fn drill_down(self, client: &Client) -> Result<DrillDownConversationsReport, HelpScoutError> {
+        let res = client.get("reports/company/drilldown.json", self)?;
+        let conversations = serde_json::from_value(res.clone())?;
+        Ok(conversations)
+    }
+}
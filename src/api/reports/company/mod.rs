@@ -0,0 +1,108 @@
+use chrono::{DateTime, Utc};
+
+use date_format::*;
+
+use super::{ReportBuilder, ReportFilters, TypeFilter, FilterFields};
+use super::conversations::ConvReportViewByType;
+
+pub mod overall;
+pub mod customers_helped;
+pub mod drill_down;
+
+//Which bucket of conversations a company drilldown lists
+api_enum! {
+    pub enum CompanyDrillDownRange {
+        Replies => "replies",
+        FirstReplyResolved => "firstReplyResolved",
+        Resolved => "resolved",
+        Responded => "responded",
+        Closed => "closed",
+    }
+}
+
+#[serde(default)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyReportBuilder {
+    #[serde(with = "date_format")]
+    pub(crate) start: DateTime<Utc>,
+    #[serde(with = "date_format")]
+    pub(crate) end: DateTime<Utc>,
+
+    pub(crate) mailboxes: Option<String>,
+    pub(crate) tags: Option<String>,
+    pub(crate) types: Option<String>,
+    pub(crate) folders: Option<String>,
+
+    // Only some reports want these
+    #[serde(with = "optional_date_format")]
+    pub(crate) previous_start: Option<DateTime<Utc>>,
+    #[serde(with = "optional_date_format")]
+    pub(crate) previous_end: Option<DateTime<Utc>>,
+
+    pub(crate) view_by: Option<ConvReportViewByType>,
+
+    pub(crate) page: Option<i32>,
+    pub(crate) rows: Option<i32>, //For drilldown: defaults to 10, maximum value is 50
+    pub(crate) range: Option<CompanyDrillDownRange>,
+}
+
+impl CompanyReportBuilder {
+    /* Set methods */
+    pub fn set_view_by(mut self, view_by: ConvReportViewByType) -> Self {
+        self.view_by = Some(view_by);
+        self
+    }
+
+    pub fn set_page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn set_rows(mut self, rows: i32) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    pub fn set_range(mut self, range: CompanyDrillDownRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+}
+
+impl ReportFilters for CompanyReportBuilder {
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a> {
+        FilterFields {
+            mailboxes: &mut self.mailboxes,
+            tags: &mut self.tags,
+            folders: &mut self.folders,
+            previous_start: &mut self.previous_start,
+            previous_end: &mut self.previous_end,
+        }
+    }
+}
+
+impl TypeFilter for CompanyReportBuilder {
+    fn types_field(&mut self) -> &mut Option<String> {
+        &mut self.types
+    }
+}
+
+impl From<ReportBuilder> for CompanyReportBuilder {
+    fn from(report: ReportBuilder) -> Self {
+        CompanyReportBuilder {
+            start: report.start,
+            end: report.end,
+            mailboxes: None,
+            tags: None,
+            types: None,
+            folders: None,
+            previous_start: report.previous_start,
+            previous_end: report.previous_end,
+            view_by: None,
+            page: None,
+            rows: None,
+            range: None,
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Company Overall Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/company/company/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::company::overall::CompanyReport;
+//!
+//! fn main() {
+//!     let report = overall_report().expect("run company overall report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.customers_helped.unwrap() > 0);
+//! }
+//!
+//! fn overall_report() -> Result<CompanyReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .company()
+//!         .overall(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! CompanyReport {
+//!     filter_tags: Some([]),
+//!     current: CompanyTimeRangeStatistics {
+//!         start_date: Some(2018-01-25T19:16:57Z),
+//!         end_date: Some(2018-02-01T19:16:57Z),
+//!         customers_helped: Some(112),
+//!         closed: Some(140),
+//!         total_replies: Some(231),
+//!         total_users: Some(4),
+//!         total_days: Some(7),
+//!         replies_per_day: Some(33.0),
+//!         replies_per_day_per_user: Some(8.25),
+//!         handle_time: Some(219s),
+//!         percent_resolved_on_first_reply: Some(40.76)
+//!     },
+//!     previous: None,
+//!     deltas: None,
+//!     users: Some([
+//!         CompanyUserStatistics {
+//!             user: 4321,
+//!             name: Some("Jane Doe"),
+//!             replies: Some(90),
+//!             previous_replies: None,
+//!             replies_per_day: Some(12.85),
+//!             customers_helped: Some(44),
+//!             happiness: Some(92.5)
+//!         },
+//!         // More
+//!     ])
+//! }
+//! ```
+
+use std::time::Duration;
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use id::UserId;
+use api::reports::FilterTag;
+use duration_format::*;
+use super::CompanyReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyReport {
+    pub filter_tags: Option<Vec<FilterTag>>,
+    pub current: CompanyTimeRangeStatistics,
+    pub previous: Option<CompanyTimeRangeStatistics>,
+    pub deltas: Option<CompanyMultipleTimeRangeStatistics>,
+    /// Per-user breakdown for the current range
+    pub users: Option<Vec<CompanyUserStatistics>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyTimeRangeStatistics {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub customers_helped: Option<i32>,
+    pub closed: Option<i32>,
+    pub total_replies: Option<i32>,
+    pub total_users: Option<i32>,
+    pub total_days: Option<i32>,
+    pub replies_per_day: Option<f64>,
+    pub replies_per_day_per_user: Option<f64>,
+    #[serde(default, with = "optional_duration_format")]
+    pub handle_time: Option<Duration>,
+    pub percent_resolved_on_first_reply: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyMultipleTimeRangeStatistics {
+    pub customers_helped: f64,
+    pub closed: f64,
+    pub total_replies: f64,
+    pub total_users: f64,
+    pub replies_per_day: f64,
+    pub replies_per_day_per_user: f64,
+    pub handle_time: f64,
+    pub percent_resolved_on_first_reply: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyUserStatistics {
+    pub user: UserId,
+    pub name: Option<String>,
+    pub replies: Option<i32>,
+    pub previous_replies: Option<i32>,
+    pub replies_per_day: Option<f64>,
+    pub customers_helped: Option<i32>,
+    pub happiness: Option<f64>,
+}
+
+impl CompanyReportBuilder {
+    pub fn overall(self, client: &Client) -> Result<CompanyReport, HelpScoutError> {
+        let res = client.get("reports/company.json", self)?;
+        let company = serde_json::from_value(res.clone())?;
+        Ok(company)
+    }
+}
@@ -7,10 +7,9 @@
 //! ```rust
 //! extern crate helpscout;
 //! extern crate chrono;
-//! extern crate time;
 //!
 //! use chrono::prelude::*;
-//! use time::Duration;
+//! use chrono::Duration;
 //!
 //! use helpscout::HelpScoutError;
 //! use helpscout::api::report;
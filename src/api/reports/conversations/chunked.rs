@@ -0,0 +1,186 @@
+//! Drill-down Over Long Ranges
+//!
+//! HelpScout caps how long a report range can be and returns at most 50
+//! drilldown rows a page. These helpers split the range into windows, page
+//! through each one and hand back every conversation once.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::api::report;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(365);
+//!     let end = Utc::now();
+//!
+//!     let conversations = report(start, end)
+//!         .conversations()
+//!         .drill_down_all(&client, Duration::days(30));
+//!
+//!     for conversation in conversations {
+//!         let conversation = conversation.expect("fetch drilldown page");
+//!         println!("#{} {}", conversation.number, conversation.subject);
+//!     }
+//! }
+//! ```
+use std::collections::{HashSet, VecDeque};
+
+use chrono::{DateTime, Duration, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use id::ConversationId;
+use super::{ConversationsReportBuilder, AbbreviatedConversationsStatistics};
+use super::drill_down::DrillDownConversationsReport;
+
+/// Most rows HelpScout will return in one drilldown page
+pub const MAX_DRILL_DOWN_ROWS: i32 = 50;
+
+/// Where [ChunkedDrillDown](struct.ChunkedDrillDown.html) gets its pages
+/// from. Closures work too, which is handy for testing.
+pub trait DrillDownSource {
+    fn fetch(&mut self, params: &ConversationsReportBuilder) -> Result<DrillDownConversationsReport, HelpScoutError>;
+}
+
+impl<F> DrillDownSource for F
+    where F: FnMut(&ConversationsReportBuilder) -> Result<DrillDownConversationsReport, HelpScoutError>
+{
+    fn fetch(&mut self, params: &ConversationsReportBuilder) -> Result<DrillDownConversationsReport, HelpScoutError> {
+        self(params)
+    }
+}
+
+/// Fetches from one of the drilldown endpoints.
+#[derive(Debug)]
+pub struct ClientDrillDown<'a> {
+    client: &'a Client,
+    path: &'static str,
+}
+
+impl<'a> DrillDownSource for ClientDrillDown<'a> {
+    fn fetch(&mut self, params: &ConversationsReportBuilder) -> Result<DrillDownConversationsReport, HelpScoutError> {
+        let res = self.client.get(self.path, params)?;
+        Ok(::serde_json::from_value(res)?)
+    }
+}
+
+/// Split `start` to `end` into back to back windows no longer than `window`.
+pub fn split_range(start: DateTime<Utc>, end: DateTime<Utc>, window: Duration) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows = Vec::new();
+    if window <= Duration::zero() {
+        return windows;
+    }
+
+    let mut from = start;
+    while from < end {
+        let to = ::std::cmp::min(from + window, end);
+        windows.push((from, to));
+        from = to;
+    }
+    windows
+}
+
+/// Iterator over every conversation in a drilldown, fetched a page at a
+/// time as it's consumed.
+///
+/// Conversations that show up in more than one window are only returned the
+/// first time. After an error the iterator ends.
+pub struct ChunkedDrillDown<S> {
+    source: S,
+    params: ConversationsReportBuilder,
+    windows: VecDeque<(DateTime<Utc>, DateTime<Utc>)>,
+    pages: i32,
+    buffer: VecDeque<AbbreviatedConversationsStatistics>,
+    seen: HashSet<ConversationId>,
+    failed: bool,
+}
+
+impl<S: DrillDownSource> ChunkedDrillDown<S> {
+    pub fn new(source: S, params: ConversationsReportBuilder, window: Duration) -> ChunkedDrillDown<S> {
+        let windows = split_range(params.start, params.end, window);
+        let mut params = params.set_rows(MAX_DRILL_DOWN_ROWS);
+        params.page = None;
+        ChunkedDrillDown {
+            source: source,
+            params: params,
+            windows: windows.into_iter().collect(),
+            pages: 0,
+            buffer: VecDeque::new(),
+            seen: HashSet::new(),
+            failed: false,
+        }
+    }
+
+    // Move on to the next page, or the first page of the next window.
+    // Returns false once everything has been fetched.
+    fn advance(&mut self) -> bool {
+        let page = self.params.page.unwrap_or(0);
+        if page > 0 && page < self.pages {
+            self.params.page = Some(page + 1);
+            return true;
+        }
+
+        match self.windows.pop_front() {
+            Some((start, end)) => {
+                self.params.start = start;
+                self.params.end = end;
+                self.params.page = Some(1);
+                true
+            },
+            None => false,
+        }
+    }
+}
+
+impl<S: DrillDownSource> Iterator for ChunkedDrillDown<S> {
+    type Item = Result<AbbreviatedConversationsStatistics, HelpScoutError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(conversation) = self.buffer.pop_front() {
+                return Some(Ok(conversation));
+            }
+            if self.failed || !self.advance() {
+                return None;
+            }
+
+            match self.source.fetch(&self.params) {
+                Ok(report) => {
+                    self.pages = report.conversations.pages;
+                    for conversation in report.conversations.results {
+                        if self.seen.insert(conversation.id) {
+                            self.buffer.push_back(conversation);
+                        }
+                    }
+                },
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(e));
+                },
+            }
+        }
+    }
+}
+
+impl ConversationsReportBuilder {
+    /// Every conversation in the [drill_down](#method.drill_down) report,
+    /// fetched `window` at a time.
+    pub fn drill_down_all<'a>(self, client: &'a Client, window: Duration) -> ChunkedDrillDown<ClientDrillDown<'a>> {
+        let source = ClientDrillDown { client: client, path: "reports/conversations/drilldown.json" };
+        ChunkedDrillDown::new(source, self, window)
+    }
+
+    /// Every conversation in the [new_drill_down](#method.new_drill_down)
+    /// report, fetched `window` at a time.
+    pub fn new_drill_down_all<'a>(self, client: &'a Client, window: Duration) -> ChunkedDrillDown<ClientDrillDown<'a>> {
+        let source = ClientDrillDown { client: client, path: "reports/conversations/new-drilldown.json" };
+        ChunkedDrillDown::new(source, self, window)
+    }
+}
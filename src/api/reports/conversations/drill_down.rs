@@ -5,13 +5,13 @@ use client::Client;
 use error::HelpScoutError;
 use super::{ConversationsReportBuilder, AbbreviatedConversationsStatistics};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DrillDownConversationsReport {
     pub conversations: DrillDownConversationsEnvelope
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DrillDownConversationsEnvelope {
     pub pages: i32,
@@ -30,10 +30,9 @@ impl ConversationsReportBuilder {
 /// ```rust
 /// extern crate helpscout;
 /// extern crate chrono;
-/// extern crate time;
 ///
 /// use chrono::prelude::*;
-/// use time::Duration;
+/// use chrono::Duration;
 ///
 /// use helpscout::HelpScoutError;
 /// use helpscout::api::report;
@@ -71,10 +70,9 @@ impl ConversationsReportBuilder {
 /// ```rust
 /// extern crate helpscout;
 /// extern crate chrono;
-/// extern crate time;
 ///
 /// use chrono::prelude::*;
-/// use time::Duration;
+/// use chrono::Duration;
 ///
 /// use helpscout::HelpScoutError;
 /// use helpscout::api::report;
@@ -0,0 +1,98 @@
+//! Conversations Custom Fields Reports
+//!
+//! API docs:
+//!
+//! - <https://developer.helpscout.com/help-desk-api/reports/conversations/custom-fields/>
+//! - <https://developer.helpscout.com/help-desk-api/reports/conversations/fields-drilldown/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::Client;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::DrillDownField;
+//!
+//! fn main() {
+//!     let client = Client::example();
+//!     let start = Utc::now() - Duration::days(30);
+//!     let end = Utc::now();
+//!
+//!     let fields = report(start, end).conversations()
+//!         .fields(&client)
+//!         .expect("run conversations custom fields report");
+//!     println!("{:#?}", fields);
+//!
+//!     if let Some(field) = fields.fields.first() {
+//!         let conversations = report(start, end).conversations()
+//!             .set_field(DrillDownField::CustomFields, field.id)
+//!             .set_rows(50)
+//!             .fields_drill_down(&client)
+//!             .expect("run conversations fields drill down report");
+//!         println!("{:#?}", conversations);
+//!     }
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! FieldStatistics {
+//!     count: 1,
+//!     fields: [
+//!         CustomFieldStatistics {
+//!             id: 1234,
+//!             name: "Plan",
+//!             mailbox_id: 5678,
+//!             values: [
+//!                 Statistics {
+//!                     id: 1,
+//!                     name: Some("Free"),
+//!                     count: 12,
+//!                     previous_count: None,
+//!                     percent: 60.0,
+//!                     previous_percent: None,
+//!                     delta_percent: None
+//!                 },
+//!                 // More
+//!             ],
+//!             summary: CustomFieldSummary {
+//!                 total: 25,
+//!                 total_answered: 20,
+//!                 previous_total: None,
+//!                 previous_total_answered: None,
+//!                 unanswered_delta: 0.0,
+//!                 unanswered_previous_percent: 0.0,
+//!                 unanswered_percent: 20.0
+//!             }
+//!         }
+//!     ]
+//! }
+//! ```
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use super::{ConversationsReportBuilder, FieldStatistics};
+use super::drill_down::DrillDownConversationsReport;
+
+impl ConversationsReportBuilder {
+    pub fn fields(self, client: &Client) -> Result<FieldStatistics, HelpScoutError> {
+        let res = client.get("reports/conversations/fields.json", self)?;
+        let fields = serde_json::from_value(res.clone())?;
+        Ok(fields)
+    }
+
+    /// Conversations behind one entry of the overall or custom fields
+    /// report, chosen with `set_field`.
+    pub fn fields_drill_down(self, client: &Client) -> Result<DrillDownConversationsReport, HelpScoutError> {
+        let res = client.get("reports/conversations/fields-drilldown.json", self)?;
+        let conversations = serde_json::from_value(res.clone())?;
+        Ok(conversations)
+    }
+}
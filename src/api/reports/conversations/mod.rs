@@ -1,22 +1,26 @@
 use chrono::{DateTime, Utc};
 
 use date_format::*;
+use id::{MailboxId, ConversationId, CustomerId, UserId};
 
-use super::ReportBuilder;
+use super::{ReportBuilder, ReportFilters, TypeFilter, FilterFields};
 
 pub mod overall;
 pub mod busy_times;
 pub mod new_conversations;
 pub mod received_messages;
 pub mod drill_down;
+pub mod chunked;
+pub mod fields;
+pub mod volume_by_channel;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopStatistics<T> {
     pub count: i64,
     pub top: Vec<T>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Statistics {
     pub id: i64,
@@ -28,23 +32,23 @@ pub struct Statistics {
     pub delta_percent: Option<f64>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldStatistics {
     pub count: i64,
     pub fields: Vec<CustomFieldStatistics>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomFieldStatistics {
     pub id: i64,
     pub name: String,
-    pub mailbox_id: i32,
+    pub mailbox_id: MailboxId,
     pub values: Vec<Statistics>,
     pub summary: CustomFieldSummary
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CustomFieldSummary {
     pub total: i64,
@@ -56,33 +60,33 @@ pub struct CustomFieldSummary {
     pub unanswered_percent: f64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BusyTimeStatistics {
     pub day: i32,
     pub hour: i32,
     pub count: i32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NewConversationsStatistics {
     pub start: DateTime<Utc>,
     pub count: i32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReceivedMessagesStatistics {
     pub date: DateTime<Utc>,
     pub messages: i32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AbbreviatedConversationsStatistics {
-    pub id: i32,
+    pub id: ConversationId,
     pub number: i32,
     #[serde(rename = "type")]
     pub conversation_type: ConversationType,
-    pub mailboxid: i32,
+    pub mailboxid: MailboxId,
     pub attachments: bool,
     pub subject: String,
     pub status: ConversationStatus,
@@ -90,17 +94,17 @@ pub struct AbbreviatedConversationsStatistics {
     pub preview: String,
     pub customer_name: String,
     pub customer_email: String,
-    pub customer_ids: Vec<i32>,
+    pub customer_ids: Vec<CustomerId>,
     pub modified_at: DateTime<Utc>,
     pub waiting_since: DateTime<Utc>,
     pub waiting_since_type: i32,
-    pub assignedid: i32,
+    pub assignedid: UserId,
     pub tags: Vec<ColorTag>,
     pub assigned_name: Option<String>, 
 }
 
 // Color tags for drill down/abbreviated conversation reports
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ColorTag {
     pub id: i32,
     pub name: String,
@@ -115,33 +119,44 @@ pub struct PreviousRange {
 }
 
 //Enum for display the interval which the statistics summarize in received/new conversations
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConvReportViewByType {
-    Day,
-    Week,
-    Month,
+api_enum! {
+    pub enum ConvReportViewByType {
+        Day => "day",
+        Week => "week",
+        Month => "month",
+    }
 }
 
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationType {
-    Email,
-    Chat,
-    Phone,
+//Which of the overall report's top lists a fields drilldown digs into
+api_enum! {
+    pub enum DrillDownField {
+        Tags => "tags",
+        Replies => "replies",
+        Workflows => "workflows",
+        Customers => "customers",
+        CustomFields => "customfields",
+    }
 }
 
-#[derive(Debug, Serialize, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum ConversationStatus {
-    Active,
-    Pending,
-    Closed,
-    Spam,
+api_enum! {
+    pub enum ConversationType {
+        Email => "email",
+        Chat => "chat",
+        Phone => "phone",
+    }
+}
+
+api_enum! {
+    pub enum ConversationStatus {
+        Active => "active",
+        Pending => "pending",
+        Closed => "closed",
+        Spam => "spam",
+    }
 }
 
 #[serde(default)]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationsReportBuilder {
     #[serde(with = "date_format")]
@@ -162,6 +177,11 @@ pub struct ConversationsReportBuilder {
     pub previous_start: Option<DateTime<Utc>>,
     #[serde(with = "optional_date_format")]
     pub previous_end: Option<DateTime<Utc>>,
+
+    // Only the fields drilldown wants these
+    pub field: Option<DrillDownField>,
+    #[serde(rename = "fieldid")]
+    pub field_id: Option<i64>,
 }
 
 impl ConversationsReportBuilder {
@@ -171,40 +191,39 @@ impl ConversationsReportBuilder {
         self
     }
 
-    pub fn set_mailboxes(mut self, mailboxes: String) -> Self {
-        self.mailboxes = Some(mailboxes);
-        self
-    }
-
-    pub fn set_tags(mut self, tags: String) -> Self {
-        self.tags = Some(tags);
-        self
-    }
-
-    pub fn set_types(mut self, types: String) -> Self {
-        self.types = Some(types);
+    pub fn set_page(mut self, page: i32) -> Self {
+        self.page = Some(page);
         self
     }
 
-    pub fn set_folders(mut self, folders: String) -> Self {
-        self.folders = Some(folders);
+    pub fn set_rows(mut self, rows: i32) -> Self {
+        self.rows = Some(rows);
         self
     }
 
-    pub fn set_previous(mut self, previous_start: DateTime<Utc>, previous_end: DateTime<Utc>) -> Self {
-        self.previous_start = Some(previous_start);
-        self.previous_end = Some(previous_end);
+    /// Pick the tag, reply, workflow, customer or custom field to drill into
+    pub fn set_field(mut self, field: DrillDownField, field_id: i64) -> Self {
+        self.field = Some(field);
+        self.field_id = Some(field_id);
         self
     }
+}
 
-    pub fn set_page(mut self, page: i32) -> Self {
-        self.page = Some(page);
-        self
+impl ReportFilters for ConversationsReportBuilder {
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a> {
+        FilterFields {
+            mailboxes: &mut self.mailboxes,
+            tags: &mut self.tags,
+            folders: &mut self.folders,
+            previous_start: &mut self.previous_start,
+            previous_end: &mut self.previous_end,
+        }
     }
+}
 
-    pub fn set_rows(mut self, rows: i32) -> Self {
-        self.rows = Some(rows);
-        self
+impl TypeFilter for ConversationsReportBuilder {
+    fn types_field(&mut self) -> &mut Option<String> {
+        &mut self.types
     }
 }
 
@@ -220,8 +239,10 @@ impl From<ReportBuilder> for ConversationsReportBuilder {
             view_by: None,
             page: None,
             rows: None,
-            previous_start: None,
-            previous_end: None,
+            previous_start: report.previous_start,
+            previous_end: report.previous_end,
+            field: None,
+            field_id: None,
         }
     }
 }
@@ -7,10 +7,9 @@
 //! ```rust
 //! extern crate helpscout;
 //! extern crate chrono;
-//! extern crate time;
 //!
 //! use chrono::prelude::*;
-//! use time::Duration;
+//! use chrono::Duration;
 //!
 //! use helpscout::HelpScoutError;
 //! use helpscout::api::report;
@@ -132,9 +131,10 @@ use chrono::{DateTime, Utc};
 use client::Client;
 use error::HelpScoutError;
 use api::reports::FilterTag;
+use id::MailboxId;
 use super::{ConversationsReportBuilder, TopStatistics, Statistics, BusyTimeStatistics};
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationsReport {
     pub filter_tags: Vec<FilterTag>,
@@ -151,7 +151,7 @@ pub struct ConversationsReport {
     pub workflows: TopStatistics<Statistics>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationsTimeRangeStatistics {
     pub start_date: DateTime<Utc>,
@@ -163,7 +163,7 @@ pub struct ConversationsTimeRangeStatistics {
     pub conversations_per_day: i64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ConversationsMultipleTimeRangeStatistics {
     pub total_conversations: f64,
@@ -173,7 +173,7 @@ pub struct ConversationsMultipleTimeRangeStatistics {
     pub conversations_per_day: f64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReplyStatistics {
     pub name: Option<String>,
@@ -182,7 +182,7 @@ pub struct ReplyStatistics {
     pub percent: f64,
     pub previous_percent: Option<f64>,
     pub delta_percent: Option<f64>,
-    pub mailbox_id: i32,
+    pub mailbox_id: MailboxId,
 }
 
 impl ConversationsReportBuilder {
@@ -0,0 +1,90 @@
+//! New Conversations by Channel Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/conversations/volume-by-channel/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::conversations::volume_by_channel::NewConversationsByChannelReport;
+//!
+//! fn main() {
+//!     let report = by_channel_report().expect("run new conversations by channel report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.len() > 0);
+//! }
+//!
+//! fn by_channel_report() -> Result<NewConversationsByChannelReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .conversations()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .new_conversations_by_channel(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! NewConversationsByChannelReport {
+//!     current: [
+//!         ChannelVolumeStatistics {
+//!             date: 2018-01-25T00:00:00Z,
+//!             email: 12,
+//!             chat: 3,
+//!             phone: 1
+//!         },
+//!         // More
+//!     ],
+//!     previous: None
+//! }
+//! ```
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use super::ConversationsReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewConversationsByChannelReport {
+    pub current: Vec<ChannelVolumeStatistics>,
+    pub previous: Option<Vec<ChannelVolumeStatistics>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelVolumeStatistics {
+    pub date: DateTime<Utc>,
+    #[serde(default)]
+    pub email: i32,
+    #[serde(default)]
+    pub chat: i32,
+    #[serde(default)]
+    pub phone: i32,
+}
+
+impl ChannelVolumeStatistics {
+    pub fn total(&self) -> i32 {
+        self.email + self.chat + self.phone
+    }
+}
+
+impl ConversationsReportBuilder {
+    pub fn new_conversations_by_channel(self, client: &Client) -> Result<NewConversationsByChannelReport, HelpScoutError> {
+        let res = client.get("reports/conversations/volume-by-channel.json", self)?;
+        let conversations = serde_json::from_value(res.clone())?;
+        Ok(conversations)
+    }
+}
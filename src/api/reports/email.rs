@@ -0,0 +1,66 @@
+//! Email Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/email/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::email::EmailReport;
+//!
+//! fn main() {
+//!     let report = email_report().expect("run email report");
+//!     println!("{:#?}", report);
+//! }
+//!
+//! fn email_report() -> Result<EmailReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .channels()
+//!         .email(&client)
+//! }
+//! ```
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use api::reports::FilterTag;
+use api::reports::conversations::BusyTimeStatistics;
+use super::channels::{ChannelReportBuilder, ChannelVolume, ChannelResponseTimes, ChannelResolution};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailReport {
+    pub filter_tags: Option<Vec<FilterTag>>,
+    pub current: EmailTimeRangeStatistics,
+    pub previous: Option<EmailTimeRangeStatistics>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailTimeRangeStatistics {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub volume: ChannelVolume,
+    pub response_times: ChannelResponseTimes,
+    pub resolution: ChannelResolution,
+    pub replies_sent: Option<i32>,
+    pub replies_to_resolve: Option<f64>,
+    #[serde(default)]
+    pub busiest_times: Vec<BusyTimeStatistics>,
+}
+
+impl ChannelReportBuilder {
+    pub fn email(self, client: &Client) -> Result<EmailReport, HelpScoutError> {
+        self.run(client, "reports/email.json")
+    }
+}
@@ -0,0 +1,260 @@
+//! Report Export
+//!
+//! Flattens report results into a [Table](struct.Table.html) of rows that can
+//! be written out as CSV or JSON Lines for spreadsheets, or as one JSON
+//! object of columns for tools that prefer a columnar layout.
+//!
+//! Column names are the API's own field names, with nested fields joined by
+//! a `.` (e.g. `user.name`). Durations are written as seconds, lists of
+//! plain values and lists of named things (like tags) are joined with `;`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use std::io;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::api::report;
+//! use helpscout::api::reports::export::ToTable;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let report = report(Utc::now() - Duration::days(7), Utc::now())
+//!         .previous_period()
+//!         .productivity()
+//!         .overall(&client)
+//!         .expect("run productivity overall report");
+//!
+//!     let table = report.to_table().expect("flatten report");
+//!     table.write_csv(io::stdout()).expect("write csv");
+//! }
+//! ```
+use std::io::Write;
+
+use serde::Serialize;
+use serde_json::{self, Map, Value};
+
+use error::HelpScoutError;
+use super::conversations::BusyTimeStatistics;
+use super::conversations::overall::ConversationsReport;
+use super::conversations::drill_down::DrillDownConversationsReport;
+use super::productivity::overall::ProductivityReport;
+use super::user::overall::UserReport;
+
+/// Rows of report data with a shared set of columns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    columns: Vec<String>,
+    rows: Vec<Vec<Value>>,
+}
+
+/// Reports that can be flattened into a [Table](struct.Table.html).
+pub trait ToTable {
+    fn to_table(&self) -> Result<Table, HelpScoutError>;
+}
+
+impl Table {
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    /// One row per record, with its fields flattened into columns.
+    pub fn from_records<T: Serialize>(records: &[T]) -> Result<Table, HelpScoutError> {
+        let mut table = Table::new();
+        for record in records {
+            table.push(Vec::new(), record)?;
+        }
+        Ok(table)
+    }
+
+    /// Add a row made of `leading` columns followed by the flattened fields
+    /// of `record`. New columns are added on the end as they're seen.
+    pub fn push<T: Serialize>(&mut self, leading: Vec<(&str, Value)>, record: &T) -> Result<(), HelpScoutError> {
+        let mut cells: Vec<(String, Value)> = leading.into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        flatten("", serde_json::to_value(record)?, &mut cells);
+
+        let mut row = vec![Value::Null; self.columns.len()];
+        for (name, value) in cells {
+            match self.columns.iter().position(|c| *c == name) {
+                Some(i) => row[i] = value,
+                None => {
+                    self.columns.push(name);
+                    row.push(value);
+                }
+            }
+        }
+        self.rows.push(row);
+        Ok(())
+    }
+
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Rows, padded out to the current number of columns.
+    pub fn rows(&self) -> Vec<Vec<Value>> {
+        self.rows.iter().map(|row| self.padded(row)).collect()
+    }
+
+    /// Every value in the named column, top to bottom.
+    pub fn column(&self, name: &str) -> Option<Vec<Value>> {
+        let i = self.columns.iter().position(|c| c == name)?;
+        Some(self.rows.iter().map(|row| row.get(i).cloned().unwrap_or(Value::Null)).collect())
+    }
+
+    /// Write a header line and one line per row. Missing values are left
+    /// empty.
+    pub fn write_csv<W: Write>(&self, mut out: W) -> Result<(), HelpScoutError> {
+        let header: Vec<String> = self.columns.iter().map(|c| csv_field(c)).collect();
+        writeln!(out, "{}", header.join(","))?;
+
+        for row in self.rows() {
+            let fields: Vec<String> = row.iter().map(|v| csv_field(&cell_text(v))).collect();
+            writeln!(out, "{}", fields.join(","))?;
+        }
+        Ok(())
+    }
+
+    /// Write one JSON object per line, keyed by column name.
+    pub fn write_json_lines<W: Write>(&self, mut out: W) -> Result<(), HelpScoutError> {
+        for row in self.rows() {
+            let mut object = Map::new();
+            for (name, value) in self.columns.iter().zip(row.into_iter()) {
+                object.insert(name.clone(), value);
+            }
+            writeln!(out, "{}", Value::Object(object))?;
+        }
+        Ok(())
+    }
+
+    /// Write a single JSON object with an array of values per column.
+    pub fn write_json_columns<W: Write>(&self, mut out: W) -> Result<(), HelpScoutError> {
+        let mut object = Map::new();
+        for name in &self.columns {
+            let values = self.column(name).unwrap_or_default();
+            object.insert(name.clone(), Value::Array(values));
+        }
+        writeln!(out, "{}", Value::Object(object))?;
+        Ok(())
+    }
+
+    fn padded(&self, row: &[Value]) -> Vec<Value> {
+        let mut row = row.to_vec();
+        row.resize(self.columns.len(), Value::Null);
+        row
+    }
+}
+
+fn flatten(prefix: &str, value: Value, cells: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let name = if prefix.is_empty() { key } else { format!("{}.{}", prefix, key) };
+                flatten(&name, value, cells);
+            }
+        },
+        Value::Array(items) => cells.push((prefix.to_string(), join_list(items))),
+        value => cells.push((prefix.to_string(), value)),
+    }
+}
+
+fn join_list(items: Vec<Value>) -> Value {
+    let mut parts = Vec::new();
+    for item in &items {
+        let part = match *item {
+            Value::Object(ref map) => match map.get("name") {
+                Some(&Value::String(ref name)) => name.clone(),
+                // Nothing sensible to show in a single cell
+                _ => return Value::String(Value::Array(items.clone()).to_string()),
+            },
+            ref scalar => cell_text(scalar),
+        };
+        parts.push(part);
+    }
+    Value::String(parts.join(";"))
+}
+
+fn cell_text(value: &Value) -> String {
+    match *value {
+        Value::Null => String::new(),
+        Value::String(ref s) => s.clone(),
+        ref other => other.to_string(),
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains(',') || text.contains('"') || text.contains('\n') || text.contains('\r') {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn period(name: &str) -> Vec<(&'static str, Value)> {
+    vec![("period", Value::String(name.to_string()))]
+}
+
+impl ToTable for ConversationsReport {
+    /// A row for the current range, and one for the previous range if it
+    /// was asked for.
+    fn to_table(&self) -> Result<Table, HelpScoutError> {
+        let mut table = Table::new();
+        table.push(period("current"), &self.current)?;
+        if let Some(ref previous) = self.previous {
+            table.push(period("previous"), previous)?;
+        }
+        Ok(table)
+    }
+}
+
+impl ToTable for ProductivityReport {
+    /// A row for the current range, and one for the previous range if it
+    /// was asked for.
+    fn to_table(&self) -> Result<Table, HelpScoutError> {
+        let mut table = Table::new();
+        table.push(period("current"), &self.current)?;
+        if let Some(ref previous) = self.previous {
+            table.push(period("previous"), previous)?;
+        }
+        Ok(table)
+    }
+}
+
+impl ToTable for UserReport {
+    /// Like the other overall reports, with the user in the leading columns.
+    fn to_table(&self) -> Result<Table, HelpScoutError> {
+        let user = |name: &str| {
+            let mut leading = period(name);
+            leading.push(("user.id", serde_json::to_value(&self.user.id).unwrap_or(Value::Null)));
+            leading.push(("user.name", Value::String(self.user.name.clone())));
+            leading
+        };
+
+        let mut table = Table::new();
+        table.push(user("current"), &self.current)?;
+        if let Some(ref previous) = self.previous {
+            table.push(user("previous"), previous)?;
+        }
+        Ok(table)
+    }
+}
+
+impl ToTable for Vec<BusyTimeStatistics> {
+    fn to_table(&self) -> Result<Table, HelpScoutError> {
+        Table::from_records(self)
+    }
+}
+
+impl ToTable for DrillDownConversationsReport {
+    /// One row per conversation on this page.
+    fn to_table(&self) -> Result<Table, HelpScoutError> {
+        Table::from_records(&self.conversations.results)
+    }
+}
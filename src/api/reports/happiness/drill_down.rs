@@ -0,0 +1,54 @@
+//! Happiness Ratings Drill-down Report
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use api::reports::conversations::drill_down::DrillDownConversationsReport;
+use super::HappinessReportBuilder;
+
+impl HappinessReportBuilder {
+/// Happiness Ratings Drilldown
+///
+/// Conversations that received a rating in the time range, optionally limited
+/// to a single score with `set_rating`.
+///
+/// API docs: <https://developer.helpscout.com/help-desk-api/reports/happiness/drilldown/>
+///
+/// ## Usage
+///
+/// ```rust
+/// extern crate helpscout;
+/// extern crate chrono;
+///
+/// use chrono::prelude::*;
+/// use chrono::Duration;
+///
+/// use helpscout::HelpScoutError;
+/// use helpscout::api::report;
+/// use helpscout::api::reports::happiness::RatingScore;
+/// use helpscout::api::reports::conversations::drill_down::DrillDownConversationsReport;
+///
+/// fn main() {
+///     let report = drill_down_report().expect("run happiness drill down report");
+///     println!("{:#?}", report);
+///     assert!(report.conversations.page > 0);
+/// }
+///
+/// fn drill_down_report() -> Result<DrillDownConversationsReport, HelpScoutError> {
+///     let client = helpscout::Client::example();
+///     let start = Utc::now() - Duration::days(40);
+///     let end = Utc::now();
+///     report(start, end)
+///         .happiness()
+///         .set_rating(RatingScore::Great)
+///         .set_rows(50)
+///         .drill_down(&client)
+/// }
+/// ```
+///
+    pub fn drill_down(self, client: &Client) -> Result<DrillDownConversationsReport, HelpScoutError> {
+        let res = client.get("reports/happiness/drilldown.json", self)?;
+        let conversations = serde_json::from_value(res.clone())?;
+        Ok(conversations)
+    }
+}
@@ -0,0 +1,161 @@
+use chrono::{DateTime, Utc};
+
+use date_format::*;
+
+use super::{ReportBuilder, ReportFilters, TypeFilter, FilterFields, SortOrder};
+
+pub mod overall;
+pub mod ratings;
+pub mod drill_down;
+
+/// A customer's happiness rating.
+///
+/// HelpScout sends these as numbers: 1 for great, 2 for okay and 3 for not
+/// good.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RatingScore {
+    Great,
+    Okay,
+    NotGood,
+    /// A value not known to this version of the library.
+    Unknown(i32),
+}
+
+impl RatingScore {
+    pub fn as_i32(&self) -> i32 {
+        match *self {
+            RatingScore::Great => 1,
+            RatingScore::Okay => 2,
+            RatingScore::NotGood => 3,
+            RatingScore::Unknown(i) => i,
+        }
+    }
+}
+
+impl From<i32> for RatingScore {
+    fn from(i: i32) -> RatingScore {
+        match i {
+            1 => RatingScore::Great,
+            2 => RatingScore::Okay,
+            3 => RatingScore::NotGood,
+            other => RatingScore::Unknown(other),
+        }
+    }
+}
+
+impl ::serde::Serialize for RatingScore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: ::serde::Serializer
+    {
+        serializer.serialize_i32(self.as_i32())
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for RatingScore {
+    fn deserialize<D>(deserializer: D) -> Result<RatingScore, D::Error>
+        where D: ::serde::Deserializer<'de>
+    {
+        <i32 as ::serde::Deserialize>::deserialize(deserializer).map(RatingScore::from)
+    }
+}
+
+api_enum! {
+    pub enum RatingSortField {
+        Number => "number",
+        ModifiedAt => "modifiedAt",
+        Rating => "rating",
+    }
+}
+
+/// Ratings sort the same way as every other report.
+pub type RatingSortOrder = SortOrder;
+
+#[serde(default)]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HappinessReportBuilder {
+    #[serde(with = "date_format")]
+    pub(crate) start: DateTime<Utc>,
+    #[serde(with = "date_format")]
+    pub(crate) end: DateTime<Utc>,
+
+    pub(crate) mailboxes: Option<String>,
+    pub(crate) tags: Option<String>,
+    pub(crate) types: Option<String>,
+    pub(crate) folders: Option<String>,
+
+    #[serde(with = "optional_date_format")]
+    pub(crate) previous_start: Option<DateTime<Utc>>,
+    #[serde(with = "optional_date_format")]
+    pub(crate) previous_end: Option<DateTime<Utc>>,
+
+    // Only the ratings and drilldown reports want these
+    pub(crate) page: Option<i32>,
+    pub(crate) rows: Option<i32>, //For drilldown: defaults to 10, maximum value is 50
+    pub(crate) rating: Option<RatingScore>,
+    pub(crate) sort_field: Option<RatingSortField>,
+    pub(crate) sort_order: Option<SortOrder>,
+}
+
+impl HappinessReportBuilder {
+    /* Set methods */
+    pub fn set_page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn set_rows(mut self, rows: i32) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Only include ratings with this score
+    pub fn set_rating(mut self, rating: RatingScore) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    pub fn set_sort(mut self, sort_field: RatingSortField, sort_order: SortOrder) -> Self {
+        self.sort_field = Some(sort_field);
+        self.sort_order = Some(sort_order);
+        self
+    }
+}
+
+impl ReportFilters for HappinessReportBuilder {
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a> {
+        FilterFields {
+            mailboxes: &mut self.mailboxes,
+            tags: &mut self.tags,
+            folders: &mut self.folders,
+            previous_start: &mut self.previous_start,
+            previous_end: &mut self.previous_end,
+        }
+    }
+}
+
+impl TypeFilter for HappinessReportBuilder {
+    fn types_field(&mut self) -> &mut Option<String> {
+        &mut self.types
+    }
+}
+
+impl From<ReportBuilder> for HappinessReportBuilder {
+    fn from(report: ReportBuilder) -> Self {
+        HappinessReportBuilder {
+            start: report.start,
+            end: report.end,
+            mailboxes: None,
+            tags: None,
+            types: None,
+            folders: None,
+            previous_start: report.previous_start,
+            previous_end: report.previous_end,
+            page: None,
+            rows: None,
+            rating: None,
+            sort_field: None,
+            sort_order: None,
+        }
+    }
+}
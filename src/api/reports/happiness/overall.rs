@@ -0,0 +1,113 @@
+//! Happiness Overall Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/happiness/happiness/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::happiness::overall::HappinessReport;
+//!
+//! fn main() {
+//!     let report = overall_report().expect("run happiness overall report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.happiness_score >= 0.0);
+//! }
+//!
+//! fn overall_report() -> Result<HappinessReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(30);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .happiness()
+//!         .overall(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! HappinessReport {
+//!     filter_tags: [],
+//!     current: HappinessTimeRangeStatistics {
+//!         start_date: 2018-01-01T00:00:00Z,
+//!         end_date: 2018-01-31T00:00:00Z,
+//!         great_count: 33,
+//!         okay_count: 4,
+//!         not_good_count: 2,
+//!         total_customers: 1220,
+//!         total_customers_with_ratings: 39,
+//!         ratings_percent: 3.19,
+//!         great_percent: 84.61,
+//!         okay_percent: 10.25,
+//!         not_good_percent: 5.12,
+//!         happiness_score: 79.48
+//!     },
+//!     previous: None,
+//!     deltas: None
+//! }
+//! ```
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use api::reports::FilterTag;
+use super::HappinessReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HappinessReport {
+    pub filter_tags: Vec<FilterTag>,
+    pub current: HappinessTimeRangeStatistics,
+    pub previous: Option<HappinessTimeRangeStatistics>,
+    pub deltas: Option<HappinessMultipleTimeRangeStatistics>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HappinessTimeRangeStatistics {
+    pub start_date: DateTime<Utc>,
+    pub end_date: DateTime<Utc>,
+    pub great_count: i64,
+    pub okay_count: i64,
+    pub not_good_count: i64,
+    pub total_customers: i64,
+    pub total_customers_with_ratings: i64,
+    pub ratings_percent: f64,
+    pub great_percent: f64,
+    pub okay_percent: f64,
+    pub not_good_percent: f64,
+    pub happiness_score: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HappinessMultipleTimeRangeStatistics {
+    pub great_count: f64,
+    pub okay_count: f64,
+    pub not_good_count: f64,
+    pub total_customers: f64,
+    pub total_customers_with_ratings: f64,
+    pub ratings_percent: f64,
+    pub great_percent: f64,
+    pub okay_percent: f64,
+    pub not_good_percent: f64,
+    pub happiness_score: f64,
+}
+
+impl HappinessReportBuilder {
+    pub fn overall(self, client: &Client) -> Result<HappinessReport, HelpScoutError> {
+        let res = client.get("reports/happiness.json", self)?;
+        let happiness = serde_json::from_value(res.clone())?;
+        Ok(happiness)
+    }
+}
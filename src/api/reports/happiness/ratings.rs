@@ -0,0 +1,104 @@
+//! Happiness Ratings Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/happiness/ratings/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::happiness::RatingScore;
+//! use helpscout::api::reports::happiness::ratings::RatingsReport;
+//!
+//! fn main() {
+//!     let report = ratings_report().expect("run happiness ratings report");
+//!     println!("{:#?}", report);
+//!     assert!(report.page > 0);
+//! }
+//!
+//! fn ratings_report() -> Result<RatingsReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(30);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .happiness()
+//!         .set_rating(RatingScore::NotGood)
+//!         .ratings(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! RatingsReport {
+//!     page: 1,
+//!     pages: 1,
+//!     count: 1,
+//!     results: [
+//!         Rating {
+//!             number: 1234,
+//!             threadid: 4321,
+//!             thread_created_at: 2018-01-30T18:41:25Z,
+//!             id: 98765,
+//!             conversation_type: Email,
+//!             rating_id: NotGood,
+//!             rating_customer_id: 1,
+//!             rating_comments: Some("Took too long"),
+//!             rating_created_at: 2018-01-30T20:01:12Z,
+//!             rating_customer_name: Some("John Smith"),
+//!             rating_user_id: 2,
+//!             rating_user_name: Some("Jane Doe")
+//!         }
+//!     ]
+//! }
+//! ```
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use id::{ConversationId, ThreadId, CustomerId, UserId};
+use api::reports::conversations::ConversationType;
+use super::{HappinessReportBuilder, RatingScore};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingsReport {
+    pub page: i32,
+    pub pages: i32,
+    pub count: i32,
+    pub results: Vec<Rating>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rating {
+    pub number: i32,
+    pub threadid: ThreadId,
+    pub thread_created_at: DateTime<Utc>,
+    pub id: ConversationId,
+    #[serde(rename = "type")]
+    pub conversation_type: ConversationType,
+    pub rating_id: RatingScore,
+    pub rating_customer_id: CustomerId,
+    pub rating_comments: Option<String>,
+    pub rating_created_at: DateTime<Utc>,
+    pub rating_customer_name: Option<String>,
+    pub rating_user_id: UserId,
+    pub rating_user_name: Option<String>,
+}
+
+impl HappinessReportBuilder {
+    pub fn ratings(self, client: &Client) -> Result<RatingsReport, HelpScoutError> {
+        let res = client.get("reports/happiness/ratings.json", self)?;
+        let ratings = serde_json::from_value(res.clone())?;
+        Ok(ratings)
+    }
+}
@@ -6,39 +6,146 @@
 //!
 //! - [Conversations](conversations/index.html)
 //! - Docs (TODO)
-//! - Happiness (TODO)
+//! - [Happiness](happiness/index.html)
 //! - [Productivity](productivity/index.html)
-//! - Company (TODO)
+//! - [Company](company/index.html)
 //! - [User](user/index.html)
+//! - [Email](email/index.html), [Chat](chat/index.html) and
+//!   [Phone](phone/index.html), sharing [channel filters](channels/index.html)
+//!
+//! Results can be written out as CSV or JSON Lines with [export](export/index.html),
+//! and busy times and volume series explored with [analysis](analysis/index.html).
+//! [scorecard](scorecard/index.html) runs the user report for a whole team.
+//!
+//! The mailbox, tag, folder and previous range filters are the same for
+//! every report, so they live on the
+//! [ReportFilters](trait.ReportFilters.html) trait. Reports that can be
+//! narrowed to conversation types also implement
+//! [TypeFilter](trait.TypeFilter.html):
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::MailboxId;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::{ReportFilters, TypeFilter};
+//! use helpscout::api::reports::conversations::ConversationType;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let report = report(Utc::now() - Duration::days(7), Utc::now())
+//!         .productivity()
+//!         .mailboxes(&[MailboxId(1234)])
+//!         .types(&[ConversationType::Email, ConversationType::Chat])
+//!         .overall(&client);
+//!     println!("{:#?}", report);
+//! }
+//! ```
+//!
+//! Ranges can be given in any time zone, or built from a preset like
+//! [last_week](struct.ReportBuilder.html#method.last_week) which lines up
+//! with midnight where your team works and fills in the previous period to
+//! compare against:
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//!
+//! use helpscout::api::reports::ReportBuilder;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let now = Local::now();
+//!     let report = ReportBuilder::last_week(&now)
+//!         .set_office_hours(true)
+//!         .productivity()
+//!         .overall(&client);
+//!     println!("{:#?}", report);
+//! }
+//! ```
+pub mod analysis;
+pub mod channels;
+pub mod chat;
+pub mod company;
 pub mod conversations;
+pub mod email;
+pub mod export;
+pub mod happiness;
+pub mod phone;
 pub mod productivity;
+pub mod scorecard;
 pub mod user;
+mod period;
+
+use std::fmt::Display;
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeZone, Utc};
 
+use id::{MailboxId, FolderId};
+
+use self::channels::ChannelReportBuilder;
+use self::company::CompanyReportBuilder;
 use self::conversations::ConversationsReportBuilder;
+use self::happiness::HappinessReportBuilder;
 use self::productivity::ProductivityReportBuilder;
+use self::conversations::ConversationType;
 use self::user::UserReportBuilder;
 
 #[derive(Debug, Clone)]
 pub struct ReportBuilder {
     pub start: DateTime<Utc>,
     pub end: DateTime<Utc>,
+    pub previous_start: Option<DateTime<Utc>>,
+    pub previous_end: Option<DateTime<Utc>>,
+    pub office_hours: Option<bool>,
 }
 
 impl ReportBuilder {
-    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> ReportBuilder {
+    /// Report on `start` to `end`. Any time zone works; HelpScout is sent
+    /// the same instants in UTC.
+    pub fn new<Tz: TimeZone>(start: DateTime<Tz>, end: DateTime<Tz>) -> ReportBuilder {
         ReportBuilder {
-            start: start,
-            end: end,
+            start: start.with_timezone(&Utc),
+            end: end.with_timezone(&Utc),
+            previous_start: None,
+            previous_end: None,
+            office_hours: None,
         }
     }
 
+    /// Only count time inside the company's office hours, for the reports
+    /// that support it.
+    pub fn set_office_hours(mut self, office_hours: bool) -> Self {
+        self.office_hours = Some(office_hours);
+        self
+    }
+
+    /// Helper for engaging the email, chat and phone reports
+    pub fn channels(self) -> ChannelReportBuilder {
+        self.into()
+    }
+
+    /// Helper for engaging the company reports
+    pub fn company(self) -> CompanyReportBuilder {
+        self.into()
+    }
+
     /// Helper for engaging the conversations reports
     pub fn conversations(self) -> ConversationsReportBuilder {
         self.into()
     }
 
+    /// Helper for engaging the happiness reports
+    pub fn happiness(self) -> HappinessReportBuilder {
+        self.into()
+    }
+
     /// Helper for engaging the productivity reports
     pub fn productivity(self) -> ProductivityReportBuilder {
         self.into()
@@ -50,8 +157,84 @@ impl ReportBuilder {
     }
 }
 
+/// Filters every report builder understands.
+///
+/// HelpScout takes these as comma separated lists; the setters do the
+/// joining. Passing an empty slice clears the filter.
+pub trait ReportFilters: Sized {
+    #[doc(hidden)]
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a>;
+
+    /// Only report on these mailboxes
+    fn mailboxes(mut self, mailboxes: &[MailboxId]) -> Self {
+        *self.filter_fields().mailboxes = join(mailboxes);
+        self
+    }
+
+    /// Only report on conversations with any of these tags
+    fn tags(mut self, tags: &[i32]) -> Self {
+        *self.filter_fields().tags = join(tags);
+        self
+    }
+
+    /// Only report on conversations in these folders
+    fn folders(mut self, folders: &[FolderId]) -> Self {
+        *self.filter_fields().folders = join(folders);
+        self
+    }
+
+    /// Compare against this earlier time range, given in any time zone
+    fn previous<Tz: TimeZone>(mut self, previous_start: DateTime<Tz>, previous_end: DateTime<Tz>) -> Self {
+        {
+            let fields = self.filter_fields();
+            *fields.previous_start = Some(previous_start.with_timezone(&Utc));
+            *fields.previous_end = Some(previous_end.with_timezone(&Utc));
+        }
+        self
+    }
+}
+
+/// The conversation type filter, for every report builder except the
+/// [channel](channels/index.html) one, whose reports each pick their own type.
+pub trait TypeFilter: ReportFilters {
+    #[doc(hidden)]
+    fn types_field(&mut self) -> &mut Option<String>;
+
+    /// Only report on these kinds of conversation
+    fn types(mut self, types: &[ConversationType]) -> Self {
+        *self.types_field() = join(types);
+        self
+    }
+}
+
+/// The builder fields behind [ReportFilters](trait.ReportFilters.html).
+#[doc(hidden)]
+pub struct FilterFields<'a> {
+    pub mailboxes: &'a mut Option<String>,
+    pub tags: &'a mut Option<String>,
+    pub folders: &'a mut Option<String>,
+    pub previous_start: &'a mut Option<DateTime<Utc>>,
+    pub previous_end: &'a mut Option<DateTime<Utc>>,
+}
+
+pub(crate) fn join<T: Display>(values: &[T]) -> Option<String> {
+    if values.is_empty() {
+        return None;
+    }
+    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    Some(values.join(","))
+}
+
+// Direction for reports that take a sortField
+api_enum! {
+    pub enum SortOrder {
+        Asc => "ASC",
+        Desc => "DESC",
+    }
+}
+
 // Tags available for reporting
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilterTag {
     pub id: i32,
     pub name: String,
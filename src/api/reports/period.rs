@@ -0,0 +1,102 @@
+//! Time zone aware report ranges
+//!
+//! HelpScout only deals in UTC instants, so "last week" for a team in
+//! Chicago has to be worked out on their wall clock first and then
+//! converted.
+use chrono::{Datelike, DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+use super::ReportBuilder;
+
+impl ReportBuilder {
+    /// The calendar week (Monday to Monday) before the one `now` falls in,
+    /// compared against the week before that.
+    pub fn last_week<Tz: TimeZone>(now: &DateTime<Tz>) -> ReportBuilder {
+        let tz = now.timezone();
+        let today = now.naive_local().date();
+        let monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+        let start = monday - Duration::days(7);
+
+        // A week with a daylight saving change isn't 168 hours, so the one
+        // before is found on the calendar too
+        let mut report = ReportBuilder::new(midnight(&tz, start), midnight(&tz, monday));
+        report.previous_start = Some(midnight(&tz, start - Duration::days(7)));
+        report.previous_end = Some(midnight(&tz, start));
+        report
+    }
+
+    /// From the first of the month up to `now`, compared against the same
+    /// stretch of the month before.
+    pub fn this_month<Tz: TimeZone>(now: &DateTime<Tz>) -> ReportBuilder {
+        let tz = now.timezone();
+        let first = first_of_month(now.naive_local().date());
+        let previous_first = add_months(first, -1);
+
+        let elapsed = now.naive_local().signed_duration_since(first.and_hms_opt(0, 0, 0).unwrap());
+        // Don't run past the end of a shorter month
+        let previous_end = ::std::cmp::min(
+            local(&tz, previous_first.and_hms_opt(0, 0, 0).unwrap() + elapsed),
+            midnight(&tz, first),
+        );
+
+        let mut report = ReportBuilder::new(midnight(&tz, first), now.with_timezone(&Utc));
+        report.previous_start = Some(midnight(&tz, previous_first));
+        report.previous_end = Some(previous_end);
+        report
+    }
+
+    /// The whole calendar month before the one `now` falls in, compared
+    /// against the month before that.
+    pub fn last_month<Tz: TimeZone>(now: &DateTime<Tz>) -> ReportBuilder {
+        let tz = now.timezone();
+        let end = first_of_month(now.naive_local().date());
+        let start = add_months(end, -1);
+
+        let mut report = ReportBuilder::new(midnight(&tz, start), midnight(&tz, end));
+        report.previous_start = Some(midnight(&tz, add_months(start, -1)));
+        report.previous_end = Some(midnight(&tz, start));
+        report
+    }
+
+    /// Compare against the range of the same length that ends where this
+    /// one starts. The length is measured in hours, not calendar days; use a
+    /// preset or [previous](trait.ReportFilters.html#method.previous) to
+    /// follow the calendar across a daylight saving change.
+    pub fn previous_period(mut self) -> Self {
+        let length = self.end.signed_duration_since(self.start);
+        self.previous_start = Some(self.start - length);
+        self.previous_end = Some(self.start);
+        self
+    }
+}
+
+fn midnight<Tz: TimeZone>(tz: &Tz, date: NaiveDate) -> DateTime<Utc> {
+    local(tz, date.and_hms_opt(0, 0, 0).unwrap())
+}
+
+// A daylight saving change can skip a wall clock time entirely; use the
+// first one that exists after it.
+fn local<Tz: TimeZone>(tz: &Tz, mut naive: NaiveDateTime) -> DateTime<Utc> {
+    loop {
+        if let Some(dt) = tz.from_local_datetime(&naive).earliest() {
+            return dt.with_timezone(&Utc);
+        }
+        naive = naive + Duration::minutes(15);
+    }
+}
+
+fn first_of_month(date: NaiveDate) -> NaiveDate {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap()
+}
+
+fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total = date.year() * 12 + date.month0() as i32 + months;
+    let (year, month) = (total / 12, total % 12 + 1);
+
+    let mut day = date.day();
+    loop {
+        if let Some(d) = NaiveDate::from_ymd_opt(year, month as u32, day) {
+            return d;
+        }
+        day -= 1;
+    }
+}
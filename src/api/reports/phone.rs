@@ -0,0 +1,66 @@
+//! Phone Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/phone/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::phone::PhoneReport;
+//!
+//! fn main() {
+//!     let report = phone_report().expect("run phone report");
+//!     println!("{:#?}", report);
+//! }
+//!
+//! fn phone_report() -> Result<PhoneReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .channels()
+//!         .phone(&client)
+//! }
+//! ```
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use api::reports::FilterTag;
+use api::reports::conversations::BusyTimeStatistics;
+use super::channels::{ChannelReportBuilder, ChannelVolume, ChannelResponseTimes, ChannelResolution};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneReport {
+    pub filter_tags: Option<Vec<FilterTag>>,
+    pub current: PhoneTimeRangeStatistics,
+    pub previous: Option<PhoneTimeRangeStatistics>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhoneTimeRangeStatistics {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub volume: ChannelVolume,
+    pub response_times: ChannelResponseTimes,
+    pub resolution: ChannelResolution,
+    pub inbound_calls: Option<i32>,
+    pub outbound_calls: Option<i32>,
+    #[serde(default)]
+    pub busiest_times: Vec<BusyTimeStatistics>,
+}
+
+impl ChannelReportBuilder {
+    pub fn phone(self, client: &Client) -> Result<PhoneReport, HelpScoutError> {
+        self.run(client, "reports/phone.json")
+    }
+}
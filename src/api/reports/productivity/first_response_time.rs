@@ -0,0 +1,68 @@
+//! Productivity First Response Time Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/productivity/first-response-time/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::productivity::TimeSeriesReport;
+//!
+//! fn main() {
+//!     let report = first_response_time_report().expect("run productivity first response time report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.len() > 0);
+//! }
+//!
+//! fn first_response_time_report() -> Result<TimeSeriesReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .productivity()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .first_response_time(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! TimeSeriesReport {
+//!     current: [
+//!         TimeStatistics {
+//!             date: 2018-01-25T00:00:00Z,
+//!             time: Some(
+//!                 Duration {
+//!                     secs: 14041,
+//!                     nanos: 0
+//!                 }
+//!             )
+//!         },
+//!         // More
+//!     ],
+//!     previous: None
+//! }
+//! ```
+
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use super::{ProductivityReportBuilder, TimeSeriesReport};
+
+impl ProductivityReportBuilder {
+    pub fn first_response_time(self, client: &Client) -> Result<TimeSeriesReport, HelpScoutError> {
+        let res = client.get("reports/productivity/first-response-time.json", self)?;
+        let report = serde_json::from_value(res.clone())?;
+        Ok(report)
+    }
+}
@@ -1,13 +1,42 @@
 use chrono::{DateTime, Utc};
+use std::time::Duration;
 
 use date_format::*;
+use duration_format::*;
 
-use super::ReportBuilder;
+use super::{ReportBuilder, ReportFilters, TypeFilter, FilterFields};
+use super::conversations::ConvReportViewByType;
 
 pub mod overall;
+pub mod first_response_time;
+pub mod replies_sent;
+pub mod resolved;
+pub mod resolution_time;
+pub mod response_time;
+
+/// A point in one of the time based series (first response, response and
+/// resolution time). The overall report's
+/// [ResponseTimeStatistics](overall/struct.ResponseTimeStatistics.html) and
+/// [HandleTimeStatistics](overall/struct.HandleTimeStatistics.html) are
+/// histograms of the whole range instead, counted by time bucket.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeStatistics {
+    pub date: DateTime<Utc>,
+    #[serde(default, with = "optional_duration_format")]
+    pub time: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeSeriesReport {
+    pub current: Vec<TimeStatistics>,
+    pub previous: Option<Vec<TimeStatistics>>,
+}
 
 #[serde(default)]
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ProductivityReportBuilder {
     #[serde(with = "date_format")]
     pub(crate) start: DateTime<Utc>,
@@ -26,42 +55,46 @@ pub struct ProductivityReportBuilder {
     pub(crate) previous_end: Option<DateTime<Utc>>,
 
     pub(crate) office_hours: Option<i32>,
+    pub(crate) view_by: Option<ConvReportViewByType>,
 }
 
 impl ProductivityReportBuilder {
     /* Set methods */
-    pub fn mailboxes(mut self, mailboxes: String) -> Self {
-        self.mailboxes = Some(mailboxes);
+    pub fn set_office_hours(mut self, office_hours: bool) -> Self {
+        self.office_hours = Some(match office_hours {
+            true => 1,
+            false => 0,
+        });
         self
     }
 
-    pub fn tags(mut self, tags: String) -> Self {
-        self.tags = Some(tags);
-        self
+    #[deprecated(note = "use set_office_hours")]
+    pub fn office_hours(self, office_hours: bool) -> Self {
+        self.set_office_hours(office_hours)
     }
 
-    pub fn types(mut self, types: String) -> Self {
-        self.types = Some(types);
-        self
-    }
-
-    pub fn folders(mut self, folders: String) -> Self {
-        self.folders = Some(folders);
+    /// Interval for the sub-report time series
+    pub fn set_view_by(mut self, view_by: ConvReportViewByType) -> Self {
+        self.view_by = Some(view_by);
         self
     }
+}
 
-    pub fn previous(mut self, previous_start: DateTime<Utc>, previous_end: DateTime<Utc>) -> Self {
-        self.previous_start = Some(previous_start);
-        self.previous_end = Some(previous_end);
-        self
+impl ReportFilters for ProductivityReportBuilder {
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a> {
+        FilterFields {
+            mailboxes: &mut self.mailboxes,
+            tags: &mut self.tags,
+            folders: &mut self.folders,
+            previous_start: &mut self.previous_start,
+            previous_end: &mut self.previous_end,
+        }
     }
+}
 
-    pub fn office_hours(mut self, office_hours: bool) -> Self {
-        self.office_hours = Some(match office_hours {
-            true => 1,
-            false => 0,
-        });
-        self
+impl TypeFilter for ProductivityReportBuilder {
+    fn types_field(&mut self) -> &mut Option<String> {
+        &mut self.types
     }
 }
 
@@ -74,9 +107,10 @@ impl From<ReportBuilder> for ProductivityReportBuilder {
             tags: None,
             types: None,
             folders: None,
-            previous_start: None,
-            previous_end: None,
-            office_hours: None,
+            previous_start: report.previous_start,
+            previous_end: report.previous_end,
+            office_hours: report.office_hours.map(|o| o as i32),
+            view_by: None,
         }
     }
 }
@@ -7,10 +7,9 @@
 //! ```rust
 //! extern crate helpscout;
 //! extern crate chrono;
-//! extern crate time;
 //!
 //! use chrono::prelude::*;
-//! use time::Duration;
+//! use chrono::Duration;
 //!
 //! use helpscout::HelpScoutError;
 //! use helpscout::api::report;
@@ -54,25 +53,16 @@
 //!             23
 //!         ),
 //!         resolution_time: Some(
-//!             Duration {
-//!                 secs: 488475,
-//!                 nanos: 313000000
-//!             }
+//!             488475.313s
 //!         ),
 //!         replies_to_resolve: Some(
 //!             5.5384615384615383
 //!         ),
 //!         response_time: Some(
-//!             Duration {
-//!                 secs: 43369,
-//!                 nanos: 0
-//!             }
+//!             43369s
 //!         ),
 //!         first_response_time: Some(
-//!             Duration {
-//!                 secs: 14041,
-//!                 nanos: 0
-//!             }
+//!             14041s
 //!         ),
 //!         resolved: Some(
 //!             23
@@ -87,7 +77,7 @@
 //!             21
 //!         ),
 //!         handle_time: Some(
-//!             219
+//!             219s
 //!         ),
 //!         percent_resolved_on_first_reply: Some(
 //!             40.76923076923077
@@ -101,16 +91,10 @@
 //!             resolution_time: None,
 //!             replies_to_resolve: None,
 //!             response_time: Some(
-//!                 Duration {
-//!                     secs: 0,
-//!                     nanos: 0
-//!                 }
+//!                 0ns
 //!             ),
 //!             first_response_time: Some(
-//!                 Duration {
-//!                     secs: 0,
-//!                     nanos: 0
-//!                 }
+//!                 0ns
 //!             ),
 //!             resolved: None,
 //!             resolved_on_first_reply: None,
@@ -121,7 +105,7 @@
 //!                 0
 //!             ),
 //!             handle_time: Some(
-//!                 0
+//!                 0ns
 //!             ),
 //!             percent_resolved_on_first_reply: None
 //!         }
@@ -132,7 +116,7 @@
 
 use serde_json;
 use chrono::{DateTime, Utc};
-use time::Duration;
+use std::time::Duration;
 
 use client::Client;
 use error::HelpScoutError;
@@ -140,7 +124,7 @@ use api::reports::FilterTag;
 use super::{ProductivityReportBuilder};
 use duration_format::*;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProductivityReport {
     pub filter_tags: Option<Vec<FilterTag>>,
@@ -149,30 +133,31 @@ pub struct ProductivityReport {
     pub delta: Option<ProductivityMultipleTimeRangeStatistics>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProductivityTimeRangeStatistics {
     pub start_date: Option<DateTime<Utc>>,
     pub end_date: Option<DateTime<Utc>>,
     pub total_conversations: Option<i32>,
-    #[serde(with = "optional_duration_format")]
+    #[serde(default, with = "optional_duration_format")]
     pub resolution_time: Option<Duration>,
     pub replies_to_resolve: Option<f64>,
-    #[serde(with = "optional_duration_format")]
+    #[serde(default, with = "optional_duration_format")]
     pub response_time: Option<Duration>,
-    #[serde(with = "optional_duration_format")]
+    #[serde(default, with = "optional_duration_format")]
     pub first_response_time: Option<Duration>,
     pub resolved: Option<i32>,
     pub resolved_on_first_reply: Option<i32>,
     pub closed: Option<i32>,
     pub replies_sent: Option<i32>,
-    pub handle_time: Option<i32>,
+    #[serde(default, with = "optional_duration_format")]
+    pub handle_time: Option<Duration>,
     pub percent_resolved_on_first_reply: Option<f64>,
     // undocumented, but comes through. I'm not sure if it's f64 or i32 or what
     //pub ratings: Option<Vec<f64>>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ProductivityMultipleTimeRangeStatistics {
     pub total_conversations: f64,
@@ -187,7 +172,7 @@ pub struct ProductivityMultipleTimeRangeStatistics {
     pub resolution_time: f64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseTimeStatistics {
     pub count: i32,
@@ -195,7 +180,7 @@ pub struct ResponseTimeStatistics {
     pub ranges: Vec<ResponseTimeRangeStatistics>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ResponseTimeRangeStatistics {
     pub id: i32, // TODO: Convert to enum?
@@ -205,7 +190,7 @@ pub struct ResponseTimeRangeStatistics {
     pub previous_percent: f64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HandleTimeStatistics {
     pub count: i32,
@@ -213,7 +198,7 @@ pub struct HandleTimeStatistics {
     pub ranges: Vec<HandleTimeRangeStatistics>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct HandleTimeRangeStatistics {
     pub id: i32, // TODO: Convert to enum?
@@ -223,7 +208,7 @@ pub struct HandleTimeRangeStatistics {
     pub previous_percent: f64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RepliesToResolveStatistics {
     pub count: i32,
@@ -231,7 +216,7 @@ pub struct RepliesToResolveStatistics {
     pub ranges: Vec<RepliesToResolveRangeStatistics>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RepliesToResolveRangeStatistics {
     pub id: i32, // TODO: Convert to enum?
@@ -239,7 +224,8 @@ pub struct RepliesToResolveRangeStatistics {
     pub previous_count: i32,
     pub percent: f64,
     pub previous_percent: f64,
-    pub resolution_time: f64,
+    #[serde(default, with = "optional_duration_format")]
+    pub resolution_time: Option<Duration>,
 }
 
 impl ProductivityReportBuilder {
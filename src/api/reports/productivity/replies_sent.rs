@@ -0,0 +1,78 @@
+//! Productivity Replies Sent Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/productivity/replies-sent/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::productivity::replies_sent::RepliesSentReport;
+//!
+//! fn main() {
+//!     let report = replies_sent_report().expect("run productivity replies sent report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.len() > 0);
+//! }
+//!
+//! fn replies_sent_report() -> Result<RepliesSentReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .productivity()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .replies_sent(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! RepliesSentReport {
+//!     current: [
+//!         RepliesSentStatistics {
+//!             date: 2018-01-25T00:00:00Z,
+//!             replies: 21
+//!         },
+//!         // More
+//!     ],
+//!     previous: None
+//! }
+//! ```
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use super::ProductivityReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepliesSentReport {
+    pub current: Vec<RepliesSentStatistics>,
+    pub previous: Option<Vec<RepliesSentStatistics>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepliesSentStatistics {
+    pub date: DateTime<Utc>,
+    pub replies: i32,
+}
+
+impl ProductivityReportBuilder {
+    pub fn replies_sent(self, client: &Client) -> Result<RepliesSentReport, HelpScoutError> {
+        let res = client.get("reports/productivity/replies-sent.json", self)?;
+        let report = serde_json::from_value(res.clone())?;
+        Ok(report)
+    }
+}
@@ -0,0 +1,68 @@
+//! Productivity Resolution Time Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/productivity/resolution-time/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::productivity::TimeSeriesReport;
+//!
+//! fn main() {
+//!     let report = resolution_time_report().expect("run productivity resolution time report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.len() > 0);
+//! }
+//!
+//! fn resolution_time_report() -> Result<TimeSeriesReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .productivity()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .resolution_time(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! TimeSeriesReport {
+//!     current: [
+//!         TimeStatistics {
+//!             date: 2018-01-25T00:00:00Z,
+//!             time: Some(
+//!                 Duration {
+//!                     secs: 14041,
+//!                     nanos: 0
+//!                 }
+//!             )
+//!         },
+//!         // More
+//!     ],
+//!     previous: None
+//! }
+//! ```
+
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use super::{ProductivityReportBuilder, TimeSeriesReport};
+
+impl ProductivityReportBuilder {
+    pub fn resolution_time(self, client: &Client) -> Result<TimeSeriesReport, HelpScoutError> {
+        let res = client.get("reports/productivity/resolution-time.json", self)?;
+        let report = serde_json::from_value(res.clone())?;
+        Ok(report)
+    }
+}
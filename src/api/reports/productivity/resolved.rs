@@ -0,0 +1,78 @@
+//! Productivity Resolved Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/productivity/resolved/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::conversations::ConvReportViewByType;
+//! use helpscout::api::reports::productivity::resolved::ResolvedReport;
+//!
+//! fn main() {
+//!     let report = resolved_report().expect("run productivity resolved report");
+//!     println!("{:#?}", report);
+//!     assert!(report.current.len() > 0);
+//! }
+//!
+//! fn resolved_report() -> Result<ResolvedReport, HelpScoutError> {
+//!     let client = helpscout::Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     report(start, end)
+//!         .productivity()
+//!         .set_view_by(ConvReportViewByType::Day)
+//!         .resolved(&client)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! ResolvedReport {
+//!     current: [
+//!         ResolvedStatistics {
+//!             date: 2018-01-25T00:00:00Z,
+//!             resolved: 21
+//!         },
+//!         // More
+//!     ],
+//!     previous: None
+//! }
+//! ```
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use super::ProductivityReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedReport {
+    pub current: Vec<ResolvedStatistics>,
+    pub previous: Option<Vec<ResolvedStatistics>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedStatistics {
+    pub date: DateTime<Utc>,
+    pub resolved: i32,
+}
+
+impl ProductivityReportBuilder {
+    pub fn resolved(self, client: &Client) -> Result<ResolvedReport, HelpScoutError> {
+        let res = client.get("reports/productivity/resolved.json", self)?;
+        let report = serde_json::from_value(res.clone())?;
+        Ok(report)
+    }
+}
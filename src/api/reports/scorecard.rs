@@ -0,0 +1,387 @@
+//! Agent Scorecards
+//!
+//! Runs the [user report](../user/overall/index.html) for every user (or
+//! every member of a team) and ranks them side by side, with the team
+//! average, where each agent falls among their teammates, and how they
+//! moved since the previous period.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use std::io;
+//!
+//! use chrono::prelude::*;
+//!
+//! use helpscout::api::reports::ReportBuilder;
+//! use helpscout::api::reports::export::ToTable;
+//! use helpscout::api::reports::scorecard::Metric;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let scorecard = ReportBuilder::last_week(&Local::now())
+//!         .user()
+//!         .scorecard()
+//!         .rank_by(Metric::Resolved)
+//!         .concurrency(4)
+//!         .run(&client)
+//!         .expect("run scorecard");
+//!
+//!     for row in &scorecard.rows {
+//!         println!("{}. {}", row.rank, row.name);
+//!     }
+//!     println!("median replies: {:?}", scorecard.percentile(Metric::TotalReplies, 50.0));
+//!
+//!     scorecard.to_table().unwrap().write_csv(io::stdout()).unwrap();
+//! }
+//! ```
+use std::cmp::Ordering;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crossbeam;
+use serde_json::{Map, Value};
+
+use api;
+use client::Client;
+use envelope::all_pages;
+use error::HelpScoutError;
+use id::{TeamId, UserId};
+use super::export::{Table, ToTable};
+use super::user::UserReportBuilder;
+use super::user::overall::{UserReport, UserTimeRangeStatistics};
+
+/// What an agent can be scored on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Metric {
+    TotalReplies,
+    RepliesPerDay,
+    Resolved,
+    ResolvedOnFirstReply,
+    CustomersHelped,
+    HappinessScore,
+    ResponseTime,
+    ResolutionTime,
+    HandleTime,
+}
+
+impl Metric {
+    pub fn all() -> Vec<Metric> {
+        vec![
+            Metric::TotalReplies,
+            Metric::RepliesPerDay,
+            Metric::Resolved,
+            Metric::ResolvedOnFirstReply,
+            Metric::CustomersHelped,
+            Metric::HappinessScore,
+            Metric::ResponseTime,
+            Metric::ResolutionTime,
+            Metric::HandleTime,
+        ]
+    }
+
+    /// Column name used when exporting
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Metric::TotalReplies => "totalReplies",
+            Metric::RepliesPerDay => "repliesPerDay",
+            Metric::Resolved => "resolved",
+            Metric::ResolvedOnFirstReply => "percentResolvedOnFirstReply",
+            Metric::CustomersHelped => "customersHelped",
+            Metric::HappinessScore => "happinessScore",
+            Metric::ResponseTime => "responseTime",
+            Metric::ResolutionTime => "resolutionTime",
+            Metric::HandleTime => "handleTime",
+        }
+    }
+
+    /// Times are better the lower they are, everything else the higher.
+    pub fn higher_is_better(&self) -> bool {
+        match *self {
+            Metric::ResponseTime | Metric::ResolutionTime | Metric::HandleTime => false,
+            _ => true,
+        }
+    }
+
+    /// The metric's value in a user report, with times in seconds
+    pub fn value(&self, stats: &UserTimeRangeStatistics) -> Option<f64> {
+        match *self {
+            Metric::TotalReplies => Some(stats.total_replies as f64),
+            Metric::RepliesPerDay => Some(stats.replies_per_day),
+            Metric::Resolved => Some(stats.resolved as f64),
+            Metric::ResolvedOnFirstReply => Some(stats.percent_resolved_on_first_reply),
+            Metric::CustomersHelped => Some(stats.customers_helped as f64),
+            Metric::HappinessScore => Some(stats.happiness_score),
+            Metric::ResponseTime => stats.response_time.map(seconds),
+            Metric::ResolutionTime => stats.resolution_time.map(seconds),
+            Metric::HandleTime => stats.handle_time.map(seconds),
+        }
+    }
+
+    // Best first, agents without a value last
+    fn compare(&self, a: Option<f64>, b: Option<f64>) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => {
+                let order = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+                if self.higher_is_better() { order.reverse() } else { order }
+            },
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+fn seconds(d: Duration) -> f64 {
+    d.as_secs() as f64 + d.subsec_nanos() as f64 / 1e9
+}
+
+/// One agent's result for one metric.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricScore {
+    pub metric: Metric,
+    pub value: Option<f64>,
+    /// Value for the previous period, if one was asked for
+    pub previous: Option<f64>,
+    /// Percent change since the previous period
+    pub delta: Option<f64>,
+    /// Share of teammates this agent did at least as well as, 0-100
+    pub percentile: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScorecardRow {
+    /// 1 for the best agent on the ranking metric
+    pub rank: usize,
+    pub user_id: UserId,
+    pub name: String,
+    pub scores: Vec<MetricScore>,
+}
+
+impl ScorecardRow {
+    pub fn score(&self, metric: Metric) -> Option<&MetricScore> {
+        self.scores.iter().find(|s| s.metric == metric)
+    }
+}
+
+#[derive(Debug)]
+pub struct Scorecard {
+    pub rank_by: Metric,
+    pub rows: Vec<ScorecardRow>,
+    /// Users whose report couldn't be fetched
+    pub failures: Vec<(UserId, HelpScoutError)>,
+}
+
+impl Scorecard {
+    /// Rank already fetched user reports.
+    pub fn from_reports(reports: Vec<UserReport>, rank_by: Metric) -> Scorecard {
+        let metrics = Metric::all();
+
+        let mut rows: Vec<ScorecardRow> = reports.iter().map(|report| {
+            let scores = metrics.iter().map(|&metric| {
+                let value = metric.value(&report.current);
+                let previous = report.previous.as_ref().and_then(|p| metric.value(p));
+                MetricScore {
+                    metric: metric,
+                    value: value,
+                    previous: previous,
+                    delta: delta(value, previous),
+                    percentile: percentile_of(metric, value, reports.iter().map(|r| metric.value(&r.current))),
+                }
+            }).collect();
+
+            ScorecardRow {
+                rank: 0,
+                user_id: report.user.id,
+                name: report.user.name.clone(),
+                scores: scores,
+            }
+        }).collect();
+
+        rows.sort_by(|a, b| {
+            let value = |row: &ScorecardRow| row.score(rank_by).and_then(|s| s.value);
+            rank_by.compare(value(a), value(b)).then_with(|| a.name.cmp(&b.name))
+        });
+        for (i, row) in rows.iter_mut().enumerate() {
+            row.rank = i + 1;
+        }
+
+        Scorecard {
+            rank_by: rank_by,
+            rows: rows,
+            failures: Vec::new(),
+        }
+    }
+
+    fn values(&self, metric: Metric) -> Vec<f64> {
+        self.rows.iter()
+            .filter_map(|row| row.score(metric).and_then(|s| s.value))
+            .collect()
+    }
+
+    /// Team average, ignoring agents without a value.
+    pub fn average(&self, metric: Metric) -> Option<f64> {
+        let values = self.values(metric);
+        if values.is_empty() {
+            return None;
+        }
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+
+    /// The `p`th percentile (0-100) of the team's values, interpolating
+    /// between agents. `percentile(metric, 50.0)` is the median.
+    pub fn percentile(&self, metric: Metric, p: f64) -> Option<f64> {
+        let mut values = self.values(metric);
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        let p = if p < 0.0 { 0.0 } else if p > 100.0 { 100.0 } else { p };
+        let position = p / 100.0 * (values.len() - 1) as f64;
+        let (low, high) = (position.floor() as usize, position.ceil() as usize);
+        Some(values[low] + (values[high] - values[low]) * (position - low as f64))
+    }
+}
+
+fn delta(value: Option<f64>, previous: Option<f64>) -> Option<f64> {
+    match (value, previous) {
+        (Some(value), Some(previous)) if previous != 0.0 => Some((value - previous) / previous * 100.0),
+        _ => None,
+    }
+}
+
+fn percentile_of<I>(metric: Metric, value: Option<f64>, team: I) -> Option<f64>
+    where I: Iterator<Item = Option<f64>>
+{
+    let value = value?;
+    let others: Vec<f64> = team.filter_map(|v| v).collect();
+    if others.len() <= 1 {
+        return Some(100.0);
+    }
+    // Count teammates this agent matched or beat, not counting themselves
+    let beaten = others.iter()
+        .filter(|&&other| metric.compare(Some(value), Some(other)) != Ordering::Greater)
+        .count() - 1;
+    Some(beaten as f64 / (others.len() - 1) as f64 * 100.0)
+}
+
+impl ToTable for Scorecard {
+    /// A row per agent in rank order, then a `team average` row. Each metric
+    /// gets value, previous, delta and percentile columns.
+    fn to_table(&self) -> Result<Table, HelpScoutError> {
+        let number = |v: Option<f64>| v.map(Value::from).unwrap_or(Value::Null);
+        let names: Vec<(String, String, String)> = Metric::all().iter()
+            .map(|m| {
+                let name = m.name();
+                (format!("{}.previous", name), format!("{}.delta", name), format!("{}.percentile", name))
+            })
+            .collect();
+        let mut table = Table::new();
+
+        for row in &self.rows {
+            let mut cells = vec![
+                ("rank", Value::from(row.rank as u64)),
+                ("user.id", Value::from(row.user_id.0)),
+                ("user.name", Value::String(row.name.clone())),
+            ];
+            for (score, &(ref previous, ref delta, ref percentile)) in row.scores.iter().zip(names.iter()) {
+                cells.push((score.metric.name(), number(score.value)));
+                cells.push((previous.as_str(), number(score.previous)));
+                cells.push((delta.as_str(), number(score.delta)));
+                cells.push((percentile.as_str(), number(score.percentile)));
+            }
+            table.push(cells, &Map::new())?;
+        }
+
+        let mut average = vec![("user.name", Value::String("team average".into()))];
+        for metric in Metric::all() {
+            average.push((metric.name(), number(self.average(metric))));
+        }
+        table.push(average, &Map::new())?;
+
+        Ok(table)
+    }
+}
+
+/// Runs the user report for a set of agents.
+#[derive(Debug, Clone)]
+pub struct ScorecardBuilder {
+    report: UserReportBuilder,
+    team: Option<TeamId>,
+    rank_by: Metric,
+    concurrency: usize,
+}
+
+impl ScorecardBuilder {
+    /// Only score members of this team
+    pub fn team(mut self, team: TeamId) -> Self {
+        self.team = Some(team);
+        self
+    }
+
+    /// Metric to rank agents by, `Resolved` unless set
+    pub fn rank_by(mut self, metric: Metric) -> Self {
+        self.rank_by = metric;
+        self
+    }
+
+    /// How many user reports to run at once, 4 unless set
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
+        self
+    }
+
+    pub fn run(self, client: &Client) -> Result<Scorecard, HelpScoutError> {
+        let users = match self.team {
+            Some(team) => all_pages(|page| api::teams().page(page).list_team_members(client, team))?,
+            None => all_pages(|page| api::users().page(page).list(client))?,
+        };
+
+        let queue = Mutex::new(users.into_iter());
+        let results = Mutex::new(Vec::new());
+        let template = &self.report;
+
+        crossbeam::scope(|scope| {
+            for _ in 0..self.concurrency {
+                scope.spawn(|| {
+                    loop {
+                        let user = match queue.lock().unwrap().next() {
+                            Some(user) => user,
+                            None => break,
+                        };
+                        let result = template.clone().overall(client, user.id);
+                        results.lock().unwrap().push((user.id, result));
+                    }
+                });
+            }
+        });
+
+        let mut reports = Vec::new();
+        let mut failures = Vec::new();
+        for (user, result) in results.into_inner().unwrap() {
+            match result {
+                Ok(report) => reports.push(report),
+                Err(e) => failures.push((user, e)),
+            }
+        }
+
+        let mut scorecard = Scorecard::from_reports(reports, self.rank_by);
+        scorecard.failures = failures;
+        Ok(scorecard)
+    }
+}
+
+impl UserReportBuilder {
+    /// Score every user, or a team, using this report's range and filters.
+    pub fn scorecard(self) -> ScorecardBuilder {
+        ScorecardBuilder {
+            report: self,
+            team: None,
+            rank_by: Metric::Resolved,
+            concurrency: 4,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! User Conversation History Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/user/conversation-history/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::{Client, HelpScoutError};
+//! use helpscout::api::report;
+//! use helpscout::api::reports::SortOrder;
+//! use helpscout::api::reports::user::ConversationHistorySortField;
+//! use helpscout::api::reports::user::conversation_history::ConversationHistoryReport;
+//!
+//! fn main() {
+//!     let report = history_report().expect("run user conversation history report");
+//!     println!("{:#?}", report);
+//!     assert!(report.conversations.page > 0);
+//! }
+//!
+//! fn history_report() -> Result<ConversationHistoryReport, HelpScoutError> {
+//!     let client = Client::example();
+//!     let start = Utc::now() - Duration::days(7);
+//!     let end = Utc::now();
+//!     let users = helpscout::api::users().list(&client)?;
+//!     report(start, end)
+//!         .user()
+//!         .set_history_sort(ConversationHistorySortField::RepliesSent, SortOrder::Desc)
+//!         .conversation_history(&client, users.items[0].id)
+//! }
+//! ```
+//!
+//! ## Output
+//!
+//! ```rust,ignore
+//! ConversationHistoryReport {
+//!     conversations: ConversationHistoryEnvelope {
+//!         page: 1,
+//!         pages: 1,
+//!         count: 1,
+//!         results: [
+//!             ConversationHistoryStatistics {
+//!                 id: 98765,
+//!                 number: 1234,
+//!                 status: Closed,
+//!                 customer_name: Some("John Smith"),
+//!                 customer_email: Some("john@example.com"),
+//!                 replies_sent: 2,
+//!                 response_time: Some(1800s),
+//!                 resolve_time: Some(7200s),
+//!                 modified_at: 2018-01-30T20:01:12Z
+//!             }
+//!         ]
+//!     }
+//! }
+//! ```
+
+use std::time::Duration;
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use id::{ConversationId, UserId};
+use api::reports::conversations::ConversationStatus;
+use duration_format::*;
+use super::UserReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationHistoryReport {
+    pub conversations: ConversationHistoryEnvelope,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationHistoryEnvelope {
+    pub page: i32,
+    pub pages: i32,
+    pub count: i32,
+    pub results: Vec<ConversationHistoryStatistics>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationHistoryStatistics {
+    pub id: ConversationId,
+    pub number: i32,
+    pub status: ConversationStatus,
+    pub customer_name: Option<String>,
+    pub customer_email: Option<String>,
+    pub replies_sent: i32,
+    #[serde(default, with = "optional_duration_format")]
+    pub response_time: Option<Duration>,
+    #[serde(default, with = "optional_duration_format")]
+    pub resolve_time: Option<Duration>,
+    pub modified_at: DateTime<Utc>,
+}
+
+impl UserReportBuilder {
+    pub fn conversation_history(mut self, client: &Client, user: UserId) -> Result<ConversationHistoryReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/conversation-history.json", self)?;
+        let history = serde_json::from_value(res.clone())?;
+        Ok(history)
+    }
+}
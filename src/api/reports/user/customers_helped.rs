@@ -0,0 +1,22 @@
+//! User Customers Helped Report
+//!
+//! Shares its shape with the [company report](../../company/customers_helped/index.html).
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/user/customers-helped/>
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use id::UserId;
+use api::reports::company::customers_helped::CustomersHelpedReport;
+use super::UserReportBuilder;
+
+impl UserReportBuilder {
+    pub fn customers_helped(mut self, client: &Client, user: UserId) -> Result<CustomersHelpedReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/customers-helped.json", self)?;
+        let customers = serde_json::from_value(res.clone())?;
+        Ok(customers)
+    }
+}
@@ -0,0 +1,20 @@
+//! User Drill-down Report
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/user/drilldown/>
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use id::UserId;
+use api::reports::conversations::drill_down::DrillDownConversationsReport;
+use super::UserReportBuilder;
+
+impl UserReportBuilder {
+    pub fn drill_down(mut self, client: &Client, user: UserId) -> Result<DrillDownConversationsReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/drilldown.json", self)?;
+        let conversations = serde_json::from_value(res.clone())?;
+        Ok(conversations)
+    }
+}
@@ -0,0 +1,99 @@
+//! User Happiness and Happiness Drill-down Reports
+//!
+//! API docs:
+//!
+//! - <https://developer.helpscout.com/help-desk-api/reports/user/happiness/>
+//! - <https://developer.helpscout.com/help-desk-api/reports/user/ratings/>
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::Client;
+//! use helpscout::api::report;
+//! use helpscout::api::reports::happiness::RatingScore;
+//!
+//! fn main() {
+//!     let client = Client::example();
+//!     let start = Utc::now() - Duration::days(30);
+//!     let end = Utc::now();
+//!     let users = helpscout::api::users().list(&client).expect("list users");
+//!     let user = users.items[0].id;
+//!
+//!     let happiness = report(start, end).user()
+//!         .happiness(&client, user)
+//!         .expect("run user happiness report");
+//!     println!("{:#?}", happiness);
+//!
+//!     let unhappy = report(start, end).user()
+//!         .set_rating(RatingScore::NotGood)
+//!         .happiness_drill_down(&client, user)
+//!         .expect("run user happiness drill down report");
+//!     println!("{:#?}", unhappy);
+//! }
+//! ```
+
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use client::Client;
+use error::HelpScoutError;
+use id::UserId;
+use api::reports::happiness::ratings::RatingsReport;
+use super::UserReportBuilder;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserHappinessReport {
+    pub current: UserHappinessStatistics,
+    pub previous: Option<UserHappinessStatistics>,
+    pub deltas: Option<UserHappinessDeltas>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserHappinessStatistics {
+    pub start_date: Option<DateTime<Utc>>,
+    pub end_date: Option<DateTime<Utc>>,
+    pub total_customer_ratings: Option<i32>,
+    pub great_count: Option<i32>,
+    pub okay_count: Option<i32>,
+    pub not_good_count: Option<i32>,
+    pub great_percent: Option<f64>,
+    pub okay_percent: Option<f64>,
+    pub not_good_percent: Option<f64>,
+    pub happiness_score: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserHappinessDeltas {
+    pub great_count: f64,
+    pub okay_count: f64,
+    pub not_good_count: f64,
+    pub happiness_score: f64,
+}
+
+impl UserReportBuilder {
+    pub fn happiness(mut self, client: &Client, user: UserId) -> Result<UserHappinessReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/happiness.json", self)?;
+        let happiness = serde_json::from_value(res.clone())?;
+        Ok(happiness)
+    }
+
+    /// The individual ratings behind the happiness report, paginated.
+    pub fn happiness_drill_down(mut self, client: &Client, user: UserId) -> Result<RatingsReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/ratings.json", self)?;
+        let ratings = serde_json::from_value(res.clone())?;
+        Ok(ratings)
+    }
+}
@@ -1,13 +1,41 @@
 use chrono::prelude::*;
 
 use date_format::*;
+use id::UserId;
 
-use super::ReportBuilder;
+use super::{ReportBuilder, ReportFilters, TypeFilter, FilterFields, SortOrder};
+use super::conversations::{ConvReportViewByType, ConversationStatus};
+use super::happiness::{RatingScore, RatingSortField};
 
 pub mod overall;
+pub mod conversation_history;
+pub mod customers_helped;
+pub mod drill_down;
+pub mod happiness;
+pub mod replies;
+pub mod resolutions;
+
+api_enum! {
+    pub enum ConversationHistorySortField {
+        Number => "number",
+        RepliesSent => "repliesSent",
+        ResponseTime => "responseTime",
+        ResolveTime => "resolveTime",
+    }
+}
+
+// The conversation history and happiness drilldown share the sortField
+// param but not its values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub(crate) enum UserReportSortField {
+    History(ConversationHistorySortField),
+    Ratings(RatingSortField),
+}
 
 #[serde(default)]
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct UserReportBuilder {
     #[serde(with = "date_format")]
     pub(crate) start: DateTime<Utc>,
@@ -25,47 +53,89 @@ pub struct UserReportBuilder {
     #[serde(with = "optional_date_format")]
     pub(crate) previous_end: Option<DateTime<Utc>>,
 
-    pub(crate) user: i32,
+    pub(crate) user: UserId,
     pub(crate) office_hours: Option<i32>,
+    pub(crate) view_by: Option<ConvReportViewByType>,
+
+    // Paginated reports
+    pub(crate) page: Option<i32>,
+    pub(crate) rows: Option<i32>, //For drilldown: defaults to 10, maximum value is 50
+    pub(crate) status: Option<ConversationStatus>,
+    pub(crate) rating: Option<RatingScore>,
+    pub(crate) sort_field: Option<UserReportSortField>,
+    pub(crate) sort_order: Option<SortOrder>,
 }
 
 impl UserReportBuilder {
     /* Set methods */
-    pub fn set_mailboxes(mut self, mailboxes: String) -> Self {
-        self.mailboxes = Some(mailboxes);
+    pub fn set_office_hours(mut self, office_hours: bool) -> Self {
+        self.office_hours = Some(match office_hours {
+            true => 1,
+            false => 0,
+        });
         self
     }
 
-    pub fn set_tags(mut self, tags: String) -> Self {
-        self.tags = Some(tags);
+    pub fn set_view_by(mut self, view_by: ConvReportViewByType) -> Self {
+        self.view_by = Some(view_by);
         self
     }
 
-    pub fn set_types(mut self, types: String) -> Self {
-        self.types = Some(types);
+    pub fn set_page(mut self, page: i32) -> Self {
+        self.page = Some(page);
         self
     }
 
-    pub fn set_folders(mut self, folders: String) -> Self {
-        self.folders = Some(folders);
+    pub fn set_rows(mut self, rows: i32) -> Self {
+        self.rows = Some(rows);
         self
     }
 
-    pub fn set_previous(mut self, previous_start: DateTime<Utc>, previous_end: DateTime<Utc>) -> Self {
-        self.previous_start = Some(previous_start);
-        self.previous_end = Some(previous_end);
+    /// Only include conversations with this status in the conversation history
+    pub fn set_status(mut self, status: ConversationStatus) -> Self {
+        self.status = Some(status);
         self
     }
 
-    pub fn set_office_hours(mut self, office_hours: bool) -> Self {
-        self.office_hours = Some(match office_hours {
-            true => 1,
-            false => 0,
-        });
+    /// Only include ratings with this score in the happiness drilldown
+    pub fn set_rating(mut self, rating: RatingScore) -> Self {
+        self.rating = Some(rating);
+        self
+    }
+
+    /// Sort the conversation history
+    pub fn set_history_sort(mut self, sort_field: ConversationHistorySortField, sort_order: SortOrder) -> Self {
+        self.sort_field = Some(UserReportSortField::History(sort_field));
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    /// Sort the happiness drilldown
+    pub fn set_ratings_sort(mut self, sort_field: RatingSortField, sort_order: SortOrder) -> Self {
+        self.sort_field = Some(UserReportSortField::Ratings(sort_field));
+        self.sort_order = Some(sort_order);
         self
     }
 }
 
+impl ReportFilters for UserReportBuilder {
+    fn filter_fields<'a>(&'a mut self) -> FilterFields<'a> {
+        FilterFields {
+            mailboxes: &mut self.mailboxes,
+            tags: &mut self.tags,
+            folders: &mut self.folders,
+            previous_start: &mut self.previous_start,
+            previous_end: &mut self.previous_end,
+        }
+    }
+}
+
+impl TypeFilter for UserReportBuilder {
+    fn types_field(&mut self) -> &mut Option<String> {
+        &mut self.types
+    }
+}
+
 impl From<ReportBuilder> for UserReportBuilder {
     fn from(report: ReportBuilder) -> Self {
         UserReportBuilder {
@@ -75,10 +145,17 @@ impl From<ReportBuilder> for UserReportBuilder {
             tags: None,
             types: None,
             folders: None,
-            previous_start: None,
-            previous_end: None,
-            user: 0,
-            office_hours: None,
+            previous_start: report.previous_start,
+            previous_end: report.previous_end,
+            user: UserId::default(),
+            office_hours: report.office_hours.map(|o| o as i32),
+            view_by: None,
+            page: None,
+            rows: None,
+            status: None,
+            rating: None,
+            sort_field: None,
+            sort_order: None,
         }
     }
 }
@@ -7,10 +7,9 @@
 //! ```rust
 //! extern crate helpscout;
 //! extern crate chrono;
-//! extern crate time;
 //!
 //! use chrono::prelude::*;
-//! use time::Duration;
+//! use chrono::Duration;
 //!
 //! use helpscout::{Client, HelpScoutError};
 //! use helpscout::api::users::User;
@@ -72,10 +71,10 @@
 //!         resolved_on_first_reply: 0,
 //!         percent_resolved_on_first_reply: 0.0,
 //!         replies_to_resolve: 3.0,
-//!         handle_time: 123.66666666666669,
+//!         handle_time: Some(123.667s),
 //!         happiness_score: 0.0,
-//!         response_time: 42622.0,
-//!         resolution_time: 98294.0,
+//!         response_time: Some(42622s),
+//!         resolution_time: Some(98294s),
 //!         replies_per_day: 3.0,
 //!         customers_helped: 2,
 //!         total_conversations: 8,
@@ -87,15 +86,19 @@
 //! }
 //! ```
 
+use std::time::Duration;
+
 use serde_json;
 use chrono::{DateTime, Utc};
 
 use client::Client;
 use error::HelpScoutError;
 use api::reports::FilterTag;
+use id::UserId;
+use duration_format::*;
 use super::UserReportBuilder;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserReport {
     pub filter_tags: Vec<FilterTag>,
@@ -105,7 +108,7 @@ pub struct UserReport {
     pub deltas: Option<UserMultiTimeRangeStatistics>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserTimeRangeStatistics {
     pub start_date: DateTime<Utc>,
@@ -119,10 +122,13 @@ pub struct UserTimeRangeStatistics {
     pub resolved_on_first_reply: i32,
     pub percent_resolved_on_first_reply: f64,
     pub replies_to_resolve: f64,
-    pub handle_time: f64,
+    #[serde(default, with = "optional_duration_format")]
+    pub handle_time: Option<Duration>,
     pub happiness_score: f64,
-    pub response_time: f64,
-    pub resolution_time: f64,
+    #[serde(default, with = "optional_duration_format")]
+    pub response_time: Option<Duration>,
+    #[serde(default, with = "optional_duration_format")]
+    pub resolution_time: Option<Duration>,
     pub replies_per_day: f64,
     pub customers_helped: i32,
     pub total_conversations: i32,
@@ -130,7 +136,7 @@ pub struct UserTimeRangeStatistics {
     pub busiest_day: i32,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserMultiTimeRangeStatistics {
     pub total_conversations: f64,
@@ -150,22 +156,25 @@ pub struct UserMultiTimeRangeStatistics {
     pub conversations_created: f64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Rating {
     pub replies_sent: f64,
-    pub first_response_time: f64,
-    pub resolve_time: f64,
+    #[serde(default, with = "optional_duration_format")]
+    pub first_response_time: Option<Duration>,
+    #[serde(default, with = "optional_duration_format")]
+    pub resolve_time: Option<Duration>,
     pub rating_id: String,
-    pub response_time: f64,
+    #[serde(default, with = "optional_duration_format")]
+    pub response_time: Option<Duration>,
 }
 
 /// This is NOT a User. It includes additional reporting
 /// and statistics.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserDetail {
-    pub id: i32,
+    pub id: UserId,
     pub has_photo: bool,
     pub created_at: DateTime<Utc>,
     pub name: String,
@@ -174,7 +183,7 @@ pub struct UserDetail {
 }
 
 impl UserReportBuilder {
-    pub fn overall(mut self, client: &Client, user: i32) -> Result<UserReport, HelpScoutError> {
+    pub fn overall(mut self, client: &Client, user: UserId) -> Result<UserReport, HelpScoutError> {
         self.user = user;
 
         let res = client.get("reports/user.json", self)?;
@@ -0,0 +1,22 @@
+//! User Replies Report
+//!
+//! Shares its shape with the [productivity report](../../productivity/replies_sent/index.html).
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/user/replies/>
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use id::UserId;
+use api::reports::productivity::replies_sent::RepliesSentReport;
+use super::UserReportBuilder;
+
+impl UserReportBuilder {
+    pub fn replies(mut self, client: &Client, user: UserId) -> Result<RepliesSentReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/replies.json", self)?;
+        let replies = serde_json::from_value(res.clone())?;
+        Ok(replies)
+    }
+}
@@ -0,0 +1,22 @@
+//! User Resolutions Report
+//!
+//! Shares its shape with the [productivity report](../../productivity/resolved/index.html).
+//!
+//! API docs: <https://developer.helpscout.com/help-desk-api/reports/user/resolutions/>
+use serde_json;
+
+use client::Client;
+use error::HelpScoutError;
+use id::UserId;
+use api::reports::productivity::resolved::ResolvedReport;
+use super::UserReportBuilder;
+
+impl UserReportBuilder {
+    pub fn resolutions(mut self, client: &Client, user: UserId) -> Result<ResolvedReport, HelpScoutError> {
+        self.user = user;
+
+        let res = client.get("reports/user/resolutions.json", self)?;
+        let resolutions = serde_json::from_value(res.clone())?;
+        Ok(resolutions)
+    }
+}
@@ -1,10 +1,9 @@
 //! Tags Endpoints
-use serde_json;
 use chrono::{DateTime, Utc};
 
 use error::HelpScoutError;
 use client::Client;
-use envelope::{Collection};
+use envelope::{Collection, Page};
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -18,8 +17,14 @@ pub struct Tag {
     pub modified_at: Option<DateTime<Utc>>,
 }
 
-pub fn list(client: &Client) -> Result<Collection<Tag>, HelpScoutError> {
-    let res = client.get("tags.json", ())?;
-    let tags = serde_json::from_value(res.clone())?;
+/// List Tags
+///
+/// API docs:
+/// <https://developer.helpscout.com/help-desk-api/tags/list/>
+///
+/// `page` defaults to the first.
+pub fn list(client: &Client, page: Option<i32>) -> Result<Collection<Tag>, HelpScoutError> {
+    let res = client.get("tags.json", Page { page: page })?;
+    let tags = Collection::from_response(client, res)?;
     Ok(tags)
 }
\ No newline at end of file
@@ -5,6 +5,7 @@ use error::HelpScoutError;
 use client::Client;
 use envelope::{Collection, Item};
 use api::users::User;
+use id::TeamId;
 
 
 #[derive(Debug, Default, Clone, Serialize)]
@@ -80,7 +81,7 @@ impl TeamsBuilder {
     /// ```
     pub fn list(self, client: &Client) -> Result<Collection<User>, HelpScoutError> {
         let res = client.get("teams.json", self)?;
-        let teams = serde_json::from_value(res.clone())?;
+        let teams = Collection::from_response(client, res)?;
         Ok(teams)
     }
 
@@ -93,14 +94,14 @@ impl TeamsBuilder {
     /// ```rust
     /// extern crate helpscout;
     ///
-    /// use helpscout::{Client, Item, HelpScoutError};
+    /// use helpscout::{Client, Item, HelpScoutError, UserId};
     /// use helpscout::api::users::User;
     ///
     /// //Ensure you have a team created in your HelpScout control panel to test this endpoint
     /// fn main() {
     ///     let team = get_team().expect("get single team member as user object");
     ///     println!("{:#?}", team);
-    ///     assert!(team.item.id > 0);
+    ///     assert!(team.item.id.0 > 0);
     /// }
     ///
     /// fn get_team() -> Result<Item<User>, HelpScoutError> {
@@ -110,7 +111,7 @@ impl TeamsBuilder {
     /// }
     ///
     /// // Get first user so get a valid id
-    /// fn find_valid_team_user_from_list(client: &Client) -> Result<i32, HelpScoutError> {
+    /// fn find_valid_team_user_from_list(client: &Client) -> Result<UserId, HelpScoutError> {
     ///     let teams = helpscout::api::teams().list(&client)?;
     ///     Ok(teams.items[0].id)
     /// }
@@ -136,7 +137,7 @@ impl TeamsBuilder {
     ///     }
     /// }
     /// ```
-    pub fn get(self, client: &Client, id: i32) -> Result<Item<User>, HelpScoutError> {
+    pub fn get(self, client: &Client, id: TeamId) -> Result<Item<User>, HelpScoutError> {
         let res = client.get(&format!("teams/{}.json", id), ())?;
         let team = serde_json::from_value(res.clone())?;
         Ok(team)
@@ -151,7 +152,7 @@ impl TeamsBuilder {
     /// ```rust
     /// extern crate helpscout;
     ///
-    /// use helpscout::{Client, Collection, Item, HelpScoutError};
+    /// use helpscout::{Client, Collection, Item, HelpScoutError, TeamId};
     /// use helpscout::api::users::User;
     ///
     /// 
@@ -170,9 +171,9 @@ impl TeamsBuilder {
     /// }
     ///
     /// // Get first user so get a valid id
-    /// fn find_valid_member_from_list(client: &Client) -> Result<i32, HelpScoutError> {
+    /// fn find_valid_member_from_list(client: &Client) -> Result<TeamId, HelpScoutError> {
     ///     let users = helpscout::api::teams().list(&client)?;
-    ///     Ok(users.items[0].id)
+    ///     Ok(TeamId(users.items[0].id.0))
     /// }
     /// ```
     ///
@@ -202,9 +203,9 @@ impl TeamsBuilder {
     ///     ]
     /// }
     /// ```
-    pub fn list_team_members(self, client: &Client, id: i32) -> Result<Collection<User>, HelpScoutError> {
+    pub fn list_team_members(self, client: &Client, id: TeamId) -> Result<Collection<User>, HelpScoutError> {
         let res = client.get(&format!("teams/{}/members.json", id), ())?;
-        let members = serde_json::from_value(res.clone())?;
+        let members = Collection::from_response(client, res)?;
         Ok(members)
     }
 }
\ No newline at end of file
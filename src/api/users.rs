@@ -9,6 +9,7 @@ use chrono::{DateTime, Utc};
 use error::HelpScoutError;
 use client::Client;
 use envelope::{Collection, Item};
+use id::{MailboxId, UserId};
 
 #[derive(Debug, Default, Clone, Serialize)]
 pub struct UsersBuilder {
@@ -90,7 +91,7 @@ impl UsersBuilder {
     /// ```
     pub fn list(self, client: &Client) -> Result<Collection<User>, HelpScoutError> {
         let res = client.get("users.json", self)?;
-        let users = serde_json::from_value(res.clone())?;
+        let users = Collection::from_response(client, res)?;
         Ok(users)
     }
 
@@ -103,13 +104,13 @@ impl UsersBuilder {
     /// ```rust
     /// extern crate helpscout;
     ///
-    /// use helpscout::{Client, Item, HelpScoutError};
+    /// use helpscout::{Client, Item, HelpScoutError, UserId};
     /// use helpscout::api::users::User;
     ///
     /// fn main() {
     ///     let user = get_user().expect("get user");
     ///     println!("{:#?}", user);
-    ///     assert!(user.item.id > 0);
+    ///     assert!(user.item.id.0 > 0);
     /// }
     ///
     /// fn get_user() -> Result<Item<User>, HelpScoutError> {
@@ -119,7 +120,7 @@ impl UsersBuilder {
     /// }
     ///
     /// // Get first user so get a valid id
-    /// fn find_valid_user_from_list(client: &Client) -> Result<i32, HelpScoutError> {
+    /// fn find_valid_user_from_list(client: &Client) -> Result<UserId, HelpScoutError> {
     ///     let users = helpscout::api::users().list(&client)?;
     ///     Ok(users.items[0].id)
     /// }
@@ -145,7 +146,7 @@ impl UsersBuilder {
     ///     }
     /// }
     /// ```
-    pub fn get(self, client: &Client, id: i32) -> Result<Item<User>, HelpScoutError> {
+    pub fn get(self, client: &Client, id: UserId) -> Result<Item<User>, HelpScoutError> {
         let res = client.get(&format!("users/{}.json", id), ())?;
         let user = serde_json::from_value(res.clone())?;
         Ok(user)
@@ -160,7 +161,7 @@ impl UsersBuilder {
     /// ```rust
     /// extern crate helpscout;
     ///
-    /// use helpscout::{Client, Collection, HelpScoutError};
+    /// use helpscout::{Client, Collection, HelpScoutError, MailboxId};
     /// use helpscout::api::users::User;
     ///
     /// fn main() {
@@ -176,31 +177,31 @@ impl UsersBuilder {
     /// }
     ///
     /// // Get first user so get a valid id
-    /// fn find_valid_mailbox_id(client: &Client) -> Result<i32, HelpScoutError> {
-    ///     let mailboxes = helpscout::api::mailboxes::list(&client)?;
+    /// fn find_valid_mailbox_id(client: &Client) -> Result<MailboxId, HelpScoutError> {
+    ///     let mailboxes = helpscout::api::mailboxes::list(&client, None)?;
     ///     Ok(mailboxes.items[0].id)
     /// }
     /// ```
     ///
     /// Output is the same as [list](struct.UsersBuilder.html#output)
-    pub fn list_by_mailbox(self, client: &Client, mailbox_id: i32) -> Result<Collection<User>, HelpScoutError> {
+    pub fn list_by_mailbox(self, client: &Client, mailbox_id: MailboxId) -> Result<Collection<User>, HelpScoutError> {
         let res = client.get(&format!("mailboxes/{}/users.json", mailbox_id), self)?;
-        let users = serde_json::from_value(res.clone())?;
+        let users = Collection::from_response(client, res)?;
         Ok(users)
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum UserType {
-    Team,
-    User
+api_enum! {
+    pub enum UserType {
+        Team => "team",
+        User => "user",
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
-    pub id: i32,
+    pub id: UserId,
     pub first_name: String,
     pub last_name: String,
     pub email: String,
@@ -0,0 +1,307 @@
+//! Business Hours
+//!
+//! A [BusinessCalendar](struct.BusinessCalendar.html) knows when your team
+//! is working: a weekly schedule in your time zone, minus holidays. It can
+//! move a timestamp forward by an amount of working time, and measure how
+//! much working time lies between two timestamps. [SLAs](../sla/index.html)
+//! run on one.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::calendar::BusinessCalendar;
+//!
+//! fn main() {
+//!     let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+//!     let five = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+//!     let calendar = BusinessCalendar::new(FixedOffset::east_opt(3600).unwrap())
+//!         .weekdays(nine, five)
+//!         .hours(Weekday::Sat, nine, NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+//!         .holiday(NaiveDate::from_ymd_opt(2018, 12, 25).unwrap());
+//!
+//!     let received = Utc::now();
+//!     let due = calendar.add(received, Duration::hours(4)).expect("calendar to have working hours");
+//!     println!("due {}, {} working minutes from now", due, calendar.between(received, due).num_minutes());
+//! }
+//! ```
+//!
+//! Any chrono time zone works, including `Local` and the zones in the
+//! `chrono-tz` crate, which follow daylight saving time.
+//!
+//! ## Holidays
+//!
+//! [holidays_from_ics](struct.BusinessCalendar.html#method.holidays_from_ics)
+//! reads the events out of an iCalendar file, such as one exported from a
+//! shared Google or Outlook holiday calendar. Each event's days are
+//! holidays. Repeating events (`RRULE`) only count on their first date.
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use chrono::{Datelike, DateTime, Duration, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+
+use error::HelpScoutError;
+
+const DAY: u32 = 24 * 60 * 60;
+
+// Walking further than this without finding working time means the
+// schedule has no working days left.
+const MAX_DAYS: i64 = 3660;
+
+// The parts of a chrono TimeZone we need, in a form that can be boxed so the
+// calendar doesn't have to be generic.
+trait Zone: Debug + Send + Sync {
+    fn date(&self, at: DateTime<Utc>) -> NaiveDate;
+    fn to_utc(&self, local: NaiveDateTime) -> Option<DateTime<Utc>>;
+}
+
+impl<Tz> Zone for Tz
+    where Tz: TimeZone + Debug + Send + Sync
+{
+    fn date(&self, at: DateTime<Utc>) -> NaiveDate {
+        at.with_timezone(self).naive_local().date()
+    }
+
+    fn to_utc(&self, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self.from_local_datetime(&local) {
+            LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => Some(t.with_timezone(&Utc)),
+            // Skipped by daylight saving, so it starts when the clocks do
+            LocalResult::None => match self.from_local_datetime(&(local + Duration::hours(1))) {
+                LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => Some(t.with_timezone(&Utc)),
+                LocalResult::None => None,
+            },
+        }
+    }
+}
+
+/// Working hours in a time zone, minus holidays.
+#[derive(Debug, Clone)]
+pub struct BusinessCalendar {
+    zone: Arc<dyn Zone>,
+    // Open intervals per weekday, Monday first, as seconds since local midnight
+    schedule: [Vec<(u32, u32)>; 7],
+    holidays: Vec<NaiveDate>,
+}
+
+impl BusinessCalendar {
+    /// A calendar in `tz` that's closed until hours are added
+    pub fn new<Tz>(tz: Tz) -> BusinessCalendar
+        where Tz: TimeZone + Debug + Send + Sync + 'static
+    {
+        BusinessCalendar {
+            zone: Arc::new(tz),
+            schedule: Default::default(),
+            holidays: Vec::new(),
+        }
+    }
+
+    /// Open every day, all day
+    pub fn always() -> BusinessCalendar {
+        let mut calendar = BusinessCalendar::new(Utc);
+        for day in calendar.schedule.iter_mut() {
+            day.push((0, DAY));
+        }
+        calendar
+    }
+
+    /// Open from `open` to `close` local time on `day`. Can be called more
+    /// than once for a split shift. A `close` of midnight means the end of
+    /// the day, and an earlier `close` runs overnight into the next day. A
+    /// `close` equal to `open` adds nothing.
+    pub fn hours(mut self, day: Weekday, open: NaiveTime, close: NaiveTime) -> Self {
+        let open = open.num_seconds_from_midnight();
+        match close.num_seconds_from_midnight() {
+            0 => self.open(day, open, DAY),
+            close if close > open => self.open(day, open, close),
+            // Overnight, e.g. 22:00 to 06:00
+            close if close < open => {
+                self.open(day, open, DAY);
+                self.open(day.succ(), 0, close);
+            },
+            _ => {},
+        }
+        self
+    }
+
+    /// The same hours Monday to Friday
+    pub fn weekdays(self, open: NaiveTime, close: NaiveTime) -> Self {
+        [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri].iter()
+            .fold(self, |calendar, &day| calendar.hours(day, open, close))
+    }
+
+    /// A local date the calendar is closed all day
+    pub fn holiday(mut self, date: NaiveDate) -> Self {
+        if !self.holidays.contains(&date) {
+            self.holidays.push(date);
+            self.holidays.sort();
+        }
+        self
+    }
+
+    /// Add every day covered by the events in an iCalendar (`.ics`) file
+    pub fn holidays_from_ics(self, ics: &str) -> Result<Self, HelpScoutError> {
+        Ok(parse_ics(ics)?.into_iter().fold(self, |calendar, date| calendar.holiday(date)))
+    }
+
+    pub fn holidays(&self) -> &[NaiveDate] {
+        &self.holidays
+    }
+
+    /// Whether `at` is in working hours
+    pub fn is_open(&self, at: DateTime<Utc>) -> bool {
+        let date = self.zone.date(at);
+        [date.pred_opt(), Some(date)].iter()
+            .filter_map(|d| *d)
+            .flat_map(|d| self.windows(d))
+            .any(|(open, close)| open <= at && at < close)
+    }
+
+    /// Working time between `start` and `end`, zero if `end` is first
+    pub fn between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Duration {
+        let mut total = Duration::zero();
+        if end <= start {
+            return total;
+        }
+
+        let mut date = self.zone.date(start);
+        let last = self.zone.date(end);
+        while date <= last {
+            for (open, close) in self.windows(date) {
+                let from = if open > start { open } else { start };
+                let to = if close < end { close } else { end };
+                if to > from {
+                    total = total + (to - from);
+                }
+            }
+            date = match date.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        total
+    }
+
+    /// The moment `duration` of working time has passed since `start`, or
+    /// `None` if the calendar has no working time left to count
+    pub fn add(&self, start: DateTime<Utc>, duration: Duration) -> Option<DateTime<Utc>> {
+        let mut remaining = duration;
+        if remaining <= Duration::zero() {
+            return Some(start);
+        }
+
+        let mut date = self.zone.date(start);
+        for _ in 0..MAX_DAYS {
+            for (open, close) in self.windows(date) {
+                let from = if open > start { open } else { start };
+                if from < close {
+                    let available = close - from;
+                    if remaining <= available {
+                        return Some(from + remaining);
+                    }
+                    remaining = remaining - available;
+                }
+            }
+            date = match date.succ_opt() {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        None
+    }
+
+    fn open(&mut self, day: Weekday, open: u32, close: u32) {
+        let hours = &mut self.schedule[day.num_days_from_monday() as usize];
+        hours.push((open, close));
+        hours.sort();
+    }
+
+    // Open intervals on a local date, in UTC
+    fn windows(&self, date: NaiveDate) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+        if self.holidays.contains(&date) {
+            return Vec::new();
+        }
+        self.schedule[date.weekday().num_days_from_monday() as usize].iter()
+            .filter_map(|&(open, close)| {
+                Some((self.local(date, open)?, self.local(date, close)?))
+            })
+            .collect()
+    }
+
+    fn local(&self, date: NaiveDate, seconds: u32) -> Option<DateTime<Utc>> {
+        let local = date.and_hms_opt(0, 0, 0)? + Duration::seconds(seconds as i64);
+        self.zone.to_utc(local)
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        BusinessCalendar::always()
+    }
+}
+
+// The dates each VEVENT covers. All-day events end the day before DTEND.
+fn parse_ics(ics: &str) -> Result<Vec<NaiveDate>, HelpScoutError> {
+    // Long lines are folded onto following lines that start with whitespace
+    let mut lines: Vec<String> = Vec::new();
+    for line in ics.lines() {
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if let Some(last) = lines.last_mut() {
+                last.push_str(&line[1..]);
+                continue;
+            }
+        }
+        lines.push(line.to_string());
+    }
+
+    let mut dates = Vec::new();
+    let mut event: Option<(Option<NaiveDate>, Option<NaiveDate>, bool)> = None;
+    for line in &lines {
+        let (name, value) = match line.find(':') {
+            Some(i) => (&line[..i], &line[i + 1..]),
+            None => continue,
+        };
+        let mut params = name.split(';');
+        let property = params.next().unwrap_or("").to_uppercase();
+        let all_day = params.any(|p| p.eq_ignore_ascii_case("VALUE=DATE"));
+
+        match (property.as_str(), event.as_mut()) {
+            ("BEGIN", _) if value.eq_ignore_ascii_case("VEVENT") => event = Some((None, None, false)),
+            ("DTSTART", Some(event)) => {
+                event.0 = Some(parse_ics_date(value)?);
+                event.2 = all_day || value.len() == 8;
+            },
+            ("DTEND", Some(event)) => event.1 = Some(parse_ics_date(value)?),
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => {
+                if let Some((Some(start), end, all_day)) = event.take() {
+                    let last = match end {
+                        Some(end) if all_day && end > start => end.pred_opt().unwrap_or(start),
+                        Some(end) if end > start => end,
+                        _ => start,
+                    };
+                    let mut date = start;
+                    while date <= last {
+                        dates.push(date);
+                        date = match date.succ_opt() {
+                            Some(next) => next,
+                            None => break,
+                        };
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    Ok(dates)
+}
+
+// The date part of a DATE (20181225) or DATE-TIME (20181225T090000Z) value
+fn parse_ics_date(value: &str) -> Result<NaiveDate, HelpScoutError> {
+    let date = value.trim().get(..8).unwrap_or("");
+    NaiveDate::parse_from_str(date, "%Y%m%d")
+        .map_err(|e| HelpScoutError::InputParseError(format!("Invalid iCalendar date '{}': {}", value, e)))
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::thread;
 use std::io::Read;
 use std::time::Duration;
@@ -27,6 +28,15 @@ pub struct Client {
     /// Duration of time to wait between retry attempts.
     pub retry_wait: u16,
 
+    /// Validate request bodies locally before sending them. Defaults to
+    /// `true`; set to `false` to leave all validation to HelpScout.
+    pub validate_requests: bool,
+
+    /// Parse collections item by item, skipping items that fail to
+    /// deserialize instead of failing the whole page. Skipped items are
+    /// reported in `Collection::skipped`. Defaults to `false`.
+    pub lenient_collections: bool,
+
     api_url: String,
     api_key: String,
     reqwest: reqwest::Client,
@@ -34,9 +44,36 @@ pub struct Client {
 
 /// Status message returned by every API request.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Status {
     pub code: Option<i32>,
     pub error: String,
+
+    /// Per-field validation messages. Only populated on 400 responses.
+    #[serde(default)]
+    pub validation_errors: Vec<FieldError>,
+}
+
+/// A single field rejected by the HelpScout validator.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldError {
+    /// Path of the offending property, e.g. `emails[0].value`.
+    #[serde(rename = "property", alias = "path")]
+    pub path: String,
+    pub message: String,
+    /// The value that was rejected, if HelpScout echoed it back.
+    #[serde(rename = "value", alias = "rejectedValue", default)]
+    pub rejected_value: Option<Value>,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.rejected_value {
+            Some(ref v) => write!(f, "{}: {} (got {})", self.path, self.message, v),
+            None => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
 }
 
 impl Client {
@@ -50,15 +87,22 @@ impl Client {
     /// fn main() {
     ///     let api_key = env::var("HELPSCOUT_API_KEY").expect("to have HELPSCOUT_API_KEY set");
     ///     let client = Client::new(&api_key);
-    ///     let mailboxes = mailboxes::list(&client);
+    ///     let mailboxes = mailboxes::list(&client, None);
     ///     assert!(mailboxes.items.len() > 0);
     /// }
     /// ```
     pub fn new(api_key: &str) -> Client {
+        Client::with_api_url(api_key, "https://api.helpscout.net/v1")
+    }
+
+    /// Create a client against a different HelpScout API, such as Docs.
+    pub(crate) fn with_api_url(api_key: &str, api_url: &str) -> Client {
         Client {
             retry_count: 3,
             retry_wait: 250,
-            api_url: "https://api.helpscout.net/v1".into(),
+            validate_requests: true,
+            lenient_collections: false,
+            api_url: api_url.into(),
             api_key: api_key.into(),
             reqwest: reqwest::Client::new(),
         }
@@ -95,6 +139,14 @@ impl Client {
         self.request(Method::Put, self.url(path, url_params)?, body)
     }
 
+    /// Send a `delete` request to the HelpScout service. This is intended to be used
+    /// by the library and not the user.
+    pub fn delete<T>(&self, path: &str, url_params: T) -> Result<Value, HelpScoutError>
+        where T: serde::Serialize
+    {
+        self.request(Method::Delete, self.url(path, url_params)?, None)
+    }
+
     fn url<T>(&self, path: &str, params: T) -> Result<Url, HelpScoutError>
         where T: serde::Serialize
     {
@@ -0,0 +1,386 @@
+//! Docs Article Endpoints
+//!
+//! - [List by Collection](struct.ArticlesBuilder.html#method.list_by_collection)
+//! - [List by Category](struct.ArticlesBuilder.html#method.list_by_category)
+//! - [Search](struct.ArticlesBuilder.html#method.search)
+//! - [List Related](struct.ArticlesBuilder.html#method.related)
+//! - [List Revisions](struct.ArticlesBuilder.html#method.revisions)
+//! - [Get](fn.get.html)
+//! - [Get Revision](fn.get_revision.html)
+//! - [Create](struct.NewArticle.html#method.send)
+//! - [Update](struct.NewArticle.html#method.update)
+//! - [Save Draft](fn.save_draft.html)
+//! - [Delete Draft](fn.delete_draft.html)
+//! - [Update View Count](fn.update_view_count.html)
+//! - [Delete](fn.delete.html)
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use error::HelpScoutError;
+use envelope::Collection;
+use id::UserId;
+use super::{DocsClient, Visibility, SortOrder, RELOAD, unwrap, unwrap_collection};
+
+api_enum! {
+    pub enum ArticleStatus {
+        Published => "published",
+        NotPublished => "notpublished",
+    }
+}
+
+api_enum! {
+    pub enum ArticleListSort {
+        Number => "number",
+        Status => "status",
+        Name => "name",
+        Popularity => "popularity",
+        CreatedAt => "createdAt",
+        UpdatedAt => "updatedAt",
+    }
+}
+
+/// Abbreviated article returned by list, search and related endpoints.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleRef {
+    pub id: String,
+    pub number: i32,
+    pub collection_id: String,
+    pub status: ArticleStatus,
+    pub has_draft: bool,
+    pub name: String,
+    pub public_url: Option<String>,
+    pub popularity: Option<f64>,
+    pub view_count: Option<i32>,
+    pub created_by: UserId,
+    pub updated_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub last_published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Article {
+    pub id: String,
+    pub number: i32,
+    pub collection_id: String,
+    pub slug: String,
+    pub status: ArticleStatus,
+    pub has_draft: bool,
+    pub name: String,
+    pub text: String,
+    pub categories: Vec<String>,
+    pub related: Option<Vec<String>>,
+    pub keywords: Option<Vec<String>>,
+    pub public_url: Option<String>,
+    pub popularity: Option<f64>,
+    pub view_count: Option<i32>,
+    pub created_by: UserId,
+    pub updated_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub last_published_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSearchResult {
+    pub id: String,
+    pub collection_id: String,
+    pub category_ids: Option<Vec<String>>,
+    pub slug: String,
+    pub number: i32,
+    pub name: String,
+    pub preview: Option<String>,
+    pub url: Option<String>,
+    pub docs_url: Option<String>,
+    pub status: ArticleStatus,
+    pub visibility: Option<Visibility>,
+    pub has_draft: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleRevisionRef {
+    pub id: String,
+    pub article_id: String,
+    pub created_by: UserId,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleRevision {
+    pub id: String,
+    pub article_id: String,
+    pub name: Option<String>,
+    pub text: String,
+    pub created_by: UserId,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ArticlesBuilder {
+    pub(crate) page: Option<i32>,
+    pub(crate) page_size: Option<i32>,
+    pub(crate) status: Option<ArticleStatus>,
+    pub(crate) sort: Option<ArticleListSort>,
+    pub(crate) order: Option<SortOrder>,
+
+    // Search only
+    pub(crate) collection_id: Option<String>,
+    pub(crate) visibility: Option<Visibility>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ListParams<'a> {
+    page: Option<i32>,
+    page_size: Option<i32>,
+    status: Option<&'a ArticleStatus>,
+    sort: Option<&'a ArticleListSort>,
+    order: Option<&'a SortOrder>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchParams<'a> {
+    query: &'a str,
+    page: Option<i32>,
+    status: Option<&'a ArticleStatus>,
+    collection_id: Option<&'a str>,
+    visibility: Option<&'a Visibility>,
+}
+
+impl ArticlesBuilder {
+    /// Set the page for list actions
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Number of articles per page, at most 100
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn status(mut self, status: ArticleStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn sort(mut self, sort: ArticleListSort, order: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self.order = Some(order);
+        self
+    }
+
+    /// Restrict a search to a single collection
+    pub fn collection_id(mut self, collection_id: &str) -> Self {
+        self.collection_id = Some(collection_id.into());
+        self
+    }
+
+    /// Restrict a search to public or private collections
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    fn list_params<'a>(&'a self) -> ListParams<'a> {
+        ListParams {
+            page: self.page,
+            page_size: self.page_size,
+            status: self.status.as_ref(),
+            sort: self.sort.as_ref(),
+            order: self.order.as_ref(),
+        }
+    }
+
+    /// List Articles in a collection
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/list/>
+    pub fn list_by_collection(self, client: &DocsClient, collection_id: &str) -> Result<Collection<ArticleRef>, HelpScoutError> {
+        let res = client.get(&format!("collections/{}/articles", collection_id), self.list_params())?;
+        unwrap_collection(res, "articles")
+    }
+
+    /// List Articles in a category
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/list/>
+    pub fn list_by_category(self, client: &DocsClient, category_id: &str) -> Result<Collection<ArticleRef>, HelpScoutError> {
+        let res = client.get(&format!("categories/{}/articles", category_id), self.list_params())?;
+        unwrap_collection(res, "articles")
+    }
+
+    /// List Related Articles
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/list-related/>
+    pub fn related(self, client: &DocsClient, article_id: &str) -> Result<Collection<ArticleRef>, HelpScoutError> {
+        let res = client.get(&format!("articles/{}/related", article_id), self.list_params())?;
+        unwrap_collection(res, "articles")
+    }
+
+    /// List Revisions of an article
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/list-revisions/>
+    pub fn revisions(self, client: &DocsClient, article_id: &str) -> Result<Collection<ArticleRevisionRef>, HelpScoutError> {
+        let res = client.get(&format!("articles/{}/revisions", article_id), self.list_params())?;
+        unwrap_collection(res, "revisions")
+    }
+
+    /// Search Articles
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/search/>
+    pub fn search(self, client: &DocsClient, query: &str) -> Result<Collection<ArticleSearchResult>, HelpScoutError> {
+        let params = SearchParams {
+            query: query,
+            page: self.page,
+            status: self.status.as_ref(),
+            collection_id: self.collection_id.as_ref().map(|s| s.as_ref()),
+            visibility: self.visibility.as_ref(),
+        };
+        let res = client.get("search/articles", params)?;
+        unwrap_collection(res, "articles")
+    }
+}
+
+/// Get Article by id or number
+///
+/// API docs: <https://developer.helpscout.com/docs-api/articles/get/>
+pub fn get(client: &DocsClient, id_or_number: &str) -> Result<Article, HelpScoutError> {
+    let res = client.get(&format!("articles/{}", id_or_number), ())?;
+    unwrap(res, "article")
+}
+
+/// Get Revision
+///
+/// API docs: <https://developer.helpscout.com/docs-api/articles/get-revision/>
+pub fn get_revision(client: &DocsClient, id: &str) -> Result<ArticleRevision, HelpScoutError> {
+    let res = client.get(&format!("revisions/{}", id), ())?;
+    unwrap(res, "revision")
+}
+
+/// Delete Article
+///
+/// API docs: <https://developer.helpscout.com/docs-api/articles/delete/>
+pub fn delete(client: &DocsClient, id: &str) -> Result<(), HelpScoutError> {
+    client.delete(&format!("articles/{}", id), ())?;
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct Draft<'a> {
+    text: &'a str,
+}
+
+/// Save Article Draft
+///
+/// API docs: <https://developer.helpscout.com/docs-api/articles/save-draft/>
+pub fn save_draft(client: &DocsClient, id: &str, text: &str) -> Result<(), HelpScoutError> {
+    let body = serde_json::to_value(&Draft { text: text })?;
+    client.put(&format!("articles/{}/drafts", id), (), Some(body.to_string()))?;
+    Ok(())
+}
+
+/// Delete Article Draft
+///
+/// API docs: <https://developer.helpscout.com/docs-api/articles/delete-draft/>
+pub fn delete_draft(client: &DocsClient, id: &str) -> Result<(), HelpScoutError> {
+    client.delete(&format!("articles/{}/drafts", id), ())?;
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct ViewCount {
+    count: i32,
+}
+
+/// Update View Count
+///
+/// API docs: <https://developer.helpscout.com/docs-api/articles/update-view-count/>
+pub fn update_view_count(client: &DocsClient, id: &str, count: i32) -> Result<(), HelpScoutError> {
+    let body = serde_json::to_value(&ViewCount { count: count })?;
+    client.put(&format!("articles/{}/views", id), (), Some(body.to_string()))?;
+    Ok(())
+}
+
+/// Body for creating or updating an article. Fields left unset are not
+/// sent, so an update keeps their current values.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewArticle {
+    pub collection_id: String,
+    pub name: String,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ArticleStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub categories: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub related: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<String>>,
+}
+
+impl NewArticle {
+    pub fn new(collection_id: &str, name: &str, text: &str) -> NewArticle {
+        NewArticle {
+            collection_id: collection_id.into(),
+            name: name.into(),
+            text: text.into(),
+            status: None,
+            slug: None,
+            categories: None,
+            related: None,
+            keywords: None,
+        }
+    }
+
+    pub fn status(&mut self, status: ArticleStatus) -> &mut NewArticle {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn slug(&mut self, slug: &str) -> &mut NewArticle {
+        self.slug = Some(slug.into());
+        self
+    }
+
+    pub fn categories(&mut self, categories: Vec<String>) -> &mut NewArticle {
+        self.categories = Some(categories);
+        self
+    }
+
+    pub fn related(&mut self, related: Vec<String>) -> &mut NewArticle {
+        self.related = Some(related);
+        self
+    }
+
+    pub fn keywords(&mut self, keywords: Vec<String>) -> &mut NewArticle {
+        self.keywords = Some(keywords);
+        self
+    }
+
+    /// Create Article
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/create/>
+    pub fn send(&self, client: &DocsClient) -> Result<Article, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.post("articles", RELOAD, Some(body.to_string()))?;
+        unwrap(res, "article")
+    }
+
+    /// Update Article
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/articles/update/>
+    pub fn update(&self, client: &DocsClient, id: &str) -> Result<Article, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.put(&format!("articles/{}", id), RELOAD, Some(body.to_string()))?;
+        unwrap(res, "article")
+    }
+}
@@ -0,0 +1,205 @@
+//! Docs Category Endpoints
+//!
+//! - [List](struct.CategoriesBuilder.html#method.list)
+//! - [Get](fn.get.html)
+//! - [Create](struct.NewCategory.html#method.send)
+//! - [Update](struct.NewCategory.html#method.update)
+//! - [Update Order](fn.update_order.html)
+//! - [Delete](fn.delete.html)
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use error::HelpScoutError;
+use envelope::Collection;
+use id::UserId;
+use super::{DocsClient, Visibility, SortOrder, RELOAD, unwrap, unwrap_collection};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    pub id: String,
+    pub number: i32,
+    pub slug: String,
+    pub visibility: Visibility,
+    pub collection_id: String,
+    pub order: i32,
+    pub default_sort: Option<ArticleSort>,
+    pub name: String,
+    pub description: Option<String>,
+    pub article_count: Option<i32>,
+    pub published_article_count: Option<i32>,
+    pub public_url: Option<String>,
+    pub created_by: UserId,
+    pub updated_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+api_enum! {
+    /// How articles within a category are ordered on the site.
+    pub enum ArticleSort {
+        Popularity => "popularity",
+        Name => "name",
+        UpdatedAt => "updatedAt",
+    }
+}
+
+api_enum! {
+    pub enum CategorySort {
+        Number => "number",
+        Order => "order",
+        Name => "name",
+        ArticleCount => "articleCount",
+        CreatedAt => "createdAt",
+        UpdatedAt => "updatedAt",
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct CategoriesBuilder {
+    pub(crate) page: Option<i32>,
+    pub(crate) sort: Option<CategorySort>,
+    pub(crate) order: Option<SortOrder>,
+}
+
+impl CategoriesBuilder {
+    /// Set the page for list actions
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn sort(mut self, sort: CategorySort, order: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self.order = Some(order);
+        self
+    }
+
+    /// List Categories in a collection
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/categories/list/>
+    pub fn list(self, client: &DocsClient, collection_id: &str) -> Result<Collection<Category>, HelpScoutError> {
+        let res = client.get(&format!("collections/{}/categories", collection_id), self)?;
+        unwrap_collection(res, "categories")
+    }
+}
+
+/// Get Category
+///
+/// API docs: <https://developer.helpscout.com/docs-api/categories/get/>
+pub fn get(client: &DocsClient, id: &str) -> Result<Category, HelpScoutError> {
+    let res = client.get(&format!("categories/{}", id), ())?;
+    unwrap(res, "category")
+}
+
+/// Delete Category
+///
+/// API docs: <https://developer.helpscout.com/docs-api/categories/delete/>
+pub fn delete(client: &DocsClient, id: &str) -> Result<(), HelpScoutError> {
+    client.delete(&format!("categories/{}", id), ())?;
+    Ok(())
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CategoryOrder<'a> {
+    categories: Vec<CategoryPosition<'a>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CategoryPosition<'a> {
+    id: &'a str,
+    order: usize,
+}
+
+/// Update Category Order
+///
+/// Orders the categories of a collection as given, starting at 1.
+///
+/// API docs: <https://developer.helpscout.com/docs-api/categories/update-order/>
+pub fn update_order(client: &DocsClient, collection_id: &str, category_ids: &[&str]) -> Result<(), HelpScoutError> {
+    let order = CategoryOrder {
+        categories: category_ids.iter()
+            .enumerate()
+            .map(|(i, id)| CategoryPosition { id: id, order: i + 1 })
+            .collect(),
+    };
+    let body = serde_json::to_value(&order)?;
+    client.put(&format!("categories/{}/order", collection_id), (), Some(body.to_string()))?;
+    Ok(())
+}
+
+/// Body for creating or updating a category. Fields left unset are not sent,
+/// so an update keeps their current values.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCategory {
+    pub collection_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort: Option<ArticleSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl NewCategory {
+    pub fn new(collection_id: &str, name: &str) -> NewCategory {
+        NewCategory {
+            collection_id: collection_id.into(),
+            name: name.into(),
+            slug: None,
+            visibility: None,
+            order: None,
+            default_sort: None,
+            description: None,
+        }
+    }
+
+    pub fn slug(&mut self, slug: &str) -> &mut NewCategory {
+        self.slug = Some(slug.into());
+        self
+    }
+
+    pub fn visibility(&mut self, visibility: Visibility) -> &mut NewCategory {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    pub fn order(&mut self, order: i32) -> &mut NewCategory {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn default_sort(&mut self, default_sort: ArticleSort) -> &mut NewCategory {
+        self.default_sort = Some(default_sort);
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut NewCategory {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Create Category
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/categories/create/>
+    pub fn send(&self, client: &DocsClient) -> Result<Category, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.post("categories", RELOAD, Some(body.to_string()))?;
+        unwrap(res, "category")
+    }
+
+    /// Update Category
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/categories/update/>
+    pub fn update(&self, client: &DocsClient, id: &str) -> Result<Category, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.put(&format!("categories/{}", id), RELOAD, Some(body.to_string()))?;
+        unwrap(res, "category")
+    }
+}
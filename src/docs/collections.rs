@@ -0,0 +1,166 @@
+//! Docs Collection Endpoints
+//!
+//! - [List](struct.CollectionsBuilder.html#method.list)
+//! - [Get](fn.get.html)
+//! - [Create](struct.NewDocsCollection.html#method.send)
+//! - [Update](struct.NewDocsCollection.html#method.update)
+//! - [Delete](fn.delete.html)
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use error::HelpScoutError;
+use envelope::Collection;
+use id::UserId;
+use super::{DocsClient, Visibility, SortOrder, RELOAD, unwrap, unwrap_collection};
+
+/// A Docs collection. Named to avoid confusion with the paging
+/// [Collection](../../struct.Collection.html) envelope.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocsCollection {
+    pub id: String,
+    pub site_id: String,
+    pub number: i32,
+    pub slug: String,
+    pub visibility: Visibility,
+    pub order: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub public_url: Option<String>,
+    pub article_count: Option<i32>,
+    pub published_article_count: Option<i32>,
+    pub created_by: UserId,
+    pub updated_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+api_enum! {
+    pub enum CollectionSort {
+        Number => "number",
+        Visibility => "visibility",
+        Order => "order",
+        Name => "name",
+        CreatedAt => "createdAt",
+        UpdatedAt => "updatedAt",
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectionsBuilder {
+    pub(crate) page: Option<i32>,
+    pub(crate) site_id: Option<String>,
+    pub(crate) visibility: Option<Visibility>,
+    pub(crate) sort: Option<CollectionSort>,
+    pub(crate) order: Option<SortOrder>,
+}
+
+impl CollectionsBuilder {
+    /// Set the page for list actions
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Only list collections belonging to this site
+    pub fn site_id(mut self, site_id: &str) -> Self {
+        self.site_id = Some(site_id.into());
+        self
+    }
+
+    pub fn visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    pub fn sort(mut self, sort: CollectionSort, order: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self.order = Some(order);
+        self
+    }
+
+    /// List Collections
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/collections/list/>
+    pub fn list(self, client: &DocsClient) -> Result<Collection<DocsCollection>, HelpScoutError> {
+        let res = client.get("collections", self)?;
+        unwrap_collection(res, "collections")
+    }
+}
+
+/// Get Collection
+///
+/// API docs: <https://developer.helpscout.com/docs-api/collections/get/>
+pub fn get(client: &DocsClient, id: &str) -> Result<DocsCollection, HelpScoutError> {
+    let res = client.get(&format!("collections/{}", id), ())?;
+    unwrap(res, "collection")
+}
+
+/// Delete Collection
+///
+/// API docs: <https://developer.helpscout.com/docs-api/collections/delete/>
+pub fn delete(client: &DocsClient, id: &str) -> Result<(), HelpScoutError> {
+    client.delete(&format!("collections/{}", id), ())?;
+    Ok(())
+}
+
+/// Body for creating or updating a collection. Fields left unset are not sent,
+/// so an update keeps their current values.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewDocsCollection {
+    pub site_id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl NewDocsCollection {
+    pub fn new(site_id: &str, name: &str) -> NewDocsCollection {
+        NewDocsCollection {
+            site_id: site_id.into(),
+            name: name.into(),
+            visibility: None,
+            order: None,
+            description: None,
+        }
+    }
+
+    pub fn visibility(&mut self, visibility: Visibility) -> &mut NewDocsCollection {
+        self.visibility = Some(visibility);
+        self
+    }
+
+    pub fn order(&mut self, order: i32) -> &mut NewDocsCollection {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut NewDocsCollection {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Create Collection
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/collections/create/>
+    pub fn send(&self, client: &DocsClient) -> Result<DocsCollection, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.post("collections", RELOAD, Some(body.to_string()))?;
+        unwrap(res, "collection")
+    }
+
+    /// Update Collection
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/collections/update/>
+    pub fn update(&self, client: &DocsClient, id: &str) -> Result<DocsCollection, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.put(&format!("collections/{}", id), RELOAD, Some(body.to_string()))?;
+        unwrap(res, "collection")
+    }
+}
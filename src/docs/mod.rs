@@ -0,0 +1,194 @@
+//! Help Scout Docs API Bindings
+//!
+//! The Docs API lives on its own host and uses its own API key, so it has a
+//! separate [DocsClient](struct.DocsClient.html). Endpoints are grouped the
+//! same way as the Help Desk bindings in [api](../api/index.html).
+//!
+//! API docs: <https://developer.helpscout.com/docs-api/>
+//!
+//! - [Sites](sites/index.html)
+//! - [Collections](collections/index.html)
+//! - [Categories](categories/index.html)
+//! - [Articles](articles/index.html)
+//! - [Redirects](redirects/index.html)
+//!
+//! With the `publish` feature, [publish](publish/index.html) syncs a directory
+//! of Markdown files to a site.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//!
+//! use helpscout::HelpScoutError;
+//! use helpscout::docs::{self, DocsClient};
+//!
+//! fn main() {
+//!     let client = DocsClient::example();
+//!     let sites = docs::sites().list(&client).expect("list sites");
+//!     assert!(sites.items.len() > 0);
+//! }
+//! ```
+pub mod sites;
+pub mod collections;
+pub mod categories;
+pub mod articles;
+pub mod redirects;
+#[cfg(feature = "publish")]
+pub mod publish;
+
+use std::env;
+
+use dotenv::dotenv;
+use serde;
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+
+use client::Client;
+use envelope::Collection;
+use error::HelpScoutError;
+
+use self::sites::SitesBuilder;
+use self::collections::CollectionsBuilder;
+use self::categories::CategoriesBuilder;
+use self::articles::ArticlesBuilder;
+
+/// Client for the Help Scout Docs API.
+///
+/// Docs uses its own API key, found under your profile's Authentication
+/// settings, and can't be used with the Help Desk endpoints.
+#[derive(Debug)]
+pub struct DocsClient {
+    client: Client,
+}
+
+impl DocsClient {
+    /// Create a new client to the Docs service.
+    pub fn new(api_key: &str) -> DocsClient {
+        DocsClient {
+            client: Client::with_api_url(api_key, "https://docsapi.helpscout.net/v1"),
+        }
+    }
+
+    /// Configure how many times to retry, and how long to wait in between,
+    /// when the service is unavailable.
+    pub fn retry(mut self, retry_count: u8, retry_wait: u16) -> DocsClient {
+        self.client.retry_count = retry_count;
+        self.client.retry_wait = retry_wait;
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn example() -> DocsClient {
+        dotenv().ok();
+        let api_key: String = env::var("HELPSCOUT_DOCS_API_KEY").expect("to have HELPSCOUT_DOCS_API_KEY set");
+        DocsClient::new(&api_key)
+    }
+
+    pub(crate) fn get<T>(&self, path: &str, url_params: T) -> Result<Value, HelpScoutError>
+        where T: serde::Serialize
+    {
+        self.client.get(path, url_params)
+    }
+
+    pub(crate) fn post<T>(&self, path: &str, url_params: T, body: Option<String>) -> Result<Value, HelpScoutError>
+        where T: serde::Serialize
+    {
+        self.client.post(path, url_params, body)
+    }
+
+    pub(crate) fn put<T>(&self, path: &str, url_params: T, body: Option<String>) -> Result<Value, HelpScoutError>
+        where T: serde::Serialize
+    {
+        self.client.put(path, url_params, body)
+    }
+
+    pub(crate) fn delete<T>(&self, path: &str, url_params: T) -> Result<Value, HelpScoutError>
+        where T: serde::Serialize
+    {
+        self.client.delete(path, url_params)
+    }
+}
+
+/// Docs wraps every response in a key named after the resource, e.g.
+/// `{"article": {...}}` or `{"articles": {"page": 1, ...}}`.
+pub(crate) fn unwrap<T>(mut res: Value, key: &str) -> Result<T, HelpScoutError>
+    where T: DeserializeOwned
+{
+    let inner = res.get_mut(key).map(Value::take).unwrap_or(Value::Null);
+    Ok(serde_json::from_value(inner)?)
+}
+
+pub(crate) fn unwrap_collection<T>(res: Value, key: &str) -> Result<Collection<T>, HelpScoutError>
+    where T: DeserializeOwned
+{
+    unwrap(res, key)
+}
+
+/// Creates ask Docs to send back the new resource instead of an empty body.
+#[derive(Debug, Serialize)]
+pub(crate) struct Reload {
+    pub reload: bool,
+}
+
+pub(crate) const RELOAD: Reload = Reload { reload: true };
+
+api_enum! {
+    pub enum Visibility {
+        Public => "public",
+        Private => "private",
+    }
+}
+
+api_enum! {
+    pub enum SortOrder {
+        Asc => "asc",
+        Desc => "desc",
+    }
+}
+
+pub fn sites() -> SitesBuilder {
+    SitesBuilder::default()
+}
+
+pub fn collections() -> CollectionsBuilder {
+    CollectionsBuilder::default()
+}
+
+pub fn categories() -> CategoriesBuilder {
+    CategoriesBuilder::default()
+}
+
+pub fn articles() -> ArticlesBuilder {
+    ArticlesBuilder::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{self, Value};
+
+    use super::{unwrap, unwrap_collection};
+    use super::sites::Site;
+    use envelope::Collection;
+    use error::HelpScoutError;
+
+    #[test]
+    fn unwraps_the_envelope_key() {
+        let res: Value = serde_json::from_str(include_str!("../../tests/fixtures/docs_site.json")).unwrap();
+        let site: Site = unwrap(res, "site").expect("site to parse");
+        assert_eq!(site.sub_domain, "helpscout");
+
+        let res: Value = serde_json::from_str(include_str!("../../tests/fixtures/docs_collections.json")).unwrap();
+        let collections: Collection<Value> = unwrap_collection(res, "collections").expect("collections to parse");
+        assert_eq!(collections.items.len(), 2);
+    }
+
+    #[test]
+    fn missing_envelope_key_is_an_error() {
+        let res: Value = serde_json::from_str(include_str!("../../tests/fixtures/docs_site.json")).unwrap();
+        match unwrap::<Site>(res, "article") {
+            Err(HelpScoutError::JsonParseError(_)) => {},
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,407 @@
+//! Publish a directory of Markdown files as Docs articles
+//!
+//! Requires the `publish` feature.
+//!
+//! Each `.md` file starts with a YAML front matter block naming where the
+//! article lives:
+//!
+//! ```markdown
+//! ---
+//! title: Getting Started
+//! collection: Product Guides
+//! category: Basics
+//! slug: getting-started
+//! keywords: [setup, install]
+//! ---
+//!
+//! # Welcome
+//! ...
+//! ```
+//!
+//! `collection` and `category` may be a name, slug or id. `slug` defaults to
+//! the file name and `status` to `published`.
+//!
+//! Publishing happens in two steps. [plan](fn.plan.html) compares the files
+//! with the articles already on the site, by slug, and returns a
+//! [Plan](struct.Plan.html) describing what would change. Printing the plan
+//! gives a dry-run report; [Plan::apply](struct.Plan.html#method.apply)
+//! carries it out. Published articles in a managed collection that have no
+//! matching file are unpublished, never deleted.
+//!
+//! Both steps talk to the site through the [Remote](trait.Remote.html)
+//! trait, which [DocsClient](../struct.DocsClient.html) implements.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! extern crate helpscout;
+//!
+//! use helpscout::docs::DocsClient;
+//! use helpscout::docs::publish;
+//!
+//! fn main() {
+//!     let client = DocsClient::example();
+//!     let articles = publish::load_dir("docs/").expect("markdown to load");
+//!     let plan = publish::plan(&client, "5214c77c45667acd25394b51", articles).expect("plan to build");
+//!     println!("{}", plan);
+//!
+//!     let dry_run = true;
+//!     if !dry_run {
+//!         plan.apply(&client).expect("plan to apply");
+//!     }
+//! }
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{html, Options, Parser};
+use serde_yaml;
+
+use envelope::all_pages;
+use error::HelpScoutError;
+use super::DocsClient;
+use super::collections::DocsCollection;
+use super::categories::Category;
+use super::articles::{self, Article, ArticleStatus, NewArticle};
+
+/// Front matter at the top of a Markdown article.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FrontMatter {
+    pub title: String,
+    pub collection: String,
+    pub category: Option<String>,
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    pub status: Option<ArticleStatus>,
+}
+
+/// A Markdown file ready to publish.
+#[derive(Debug, Clone)]
+pub struct MarkdownArticle {
+    pub path: PathBuf,
+    pub front_matter: FrontMatter,
+    pub slug: String,
+    pub html: String,
+}
+
+impl MarkdownArticle {
+    /// Parse a Markdown document with front matter. `path` is used for error
+    /// messages and as the fallback slug.
+    pub fn parse(path: &Path, source: &str) -> Result<MarkdownArticle, HelpScoutError> {
+        let (yaml, body) = split_front_matter(source).ok_or_else(|| {
+            HelpScoutError::InputParseError(format!("{}: missing front matter", path.display()))
+        })?;
+        let front_matter: FrontMatter = serde_yaml::from_str(yaml).map_err(|e| {
+            HelpScoutError::InputParseError(format!("{}: {}", path.display(), e))
+        })?;
+
+        let slug = match front_matter.slug {
+            Some(ref slug) => slug.clone(),
+            None => path.file_stem()
+                .and_then(|s| s.to_str())
+                .map(|s| s.to_string())
+                .ok_or_else(|| HelpScoutError::InputParseError(format!("{}: no slug", path.display())))?,
+        };
+
+        Ok(MarkdownArticle {
+            path: path.to_path_buf(),
+            front_matter: front_matter,
+            slug: slug,
+            html: render(body),
+        })
+    }
+
+    fn status(&self) -> ArticleStatus {
+        self.front_matter.status.clone().unwrap_or(ArticleStatus::Published)
+    }
+}
+
+// Returns the YAML between the leading `---` fences and the rest of the file.
+fn split_front_matter(source: &str) -> Option<(&str, &str)> {
+    let source = source.trim_start_matches('\u{feff}');
+    let start = source.find('\n')? + 1;
+    if source[..start].trim_end() != "---" {
+        return None;
+    }
+
+    let mut offset = start;
+    while offset < source.len() {
+        let end = source[offset..].find('\n').map(|i| offset + i + 1).unwrap_or(source.len());
+        if source[offset..end].trim_end() == "---" {
+            return Some((&source[start..offset], &source[end..]));
+        }
+        offset = end;
+    }
+    None
+}
+
+/// Render Markdown to the HTML stored as the article text.
+pub fn render(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    let mut out = String::new();
+    html::push_html(&mut out, Parser::new_ext(markdown, options));
+    out
+}
+
+/// Load every `.md` file under `dir`, recursively, sorted by path.
+pub fn load_dir<P: AsRef<Path>>(dir: P) -> Result<Vec<MarkdownArticle>, HelpScoutError> {
+    let mut paths = Vec::new();
+    collect_markdown(dir.as_ref(), &mut paths)?;
+    paths.sort();
+
+    let mut articles = Vec::with_capacity(paths.len());
+    for path in paths {
+        let source = fs::read_to_string(&path)?;
+        articles.push(MarkdownArticle::parse(&path, &source)?);
+    }
+    Ok(articles)
+}
+
+fn collect_markdown(dir: &Path, paths: &mut Vec<PathBuf>) -> Result<(), HelpScoutError> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_markdown(&path, paths)?;
+        } else if path.extension().map_or(false, |ext| ext == "md") {
+            paths.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The site's articles as [plan](fn.plan.html) and
+/// [Plan::apply](struct.Plan.html#method.apply) see them.
+pub trait Remote {
+    /// Every collection on a site.
+    fn collections(&self, site_id: &str) -> Result<Vec<DocsCollection>, HelpScoutError>;
+
+    /// Every category in a collection.
+    fn categories(&self, collection_id: &str) -> Result<Vec<Category>, HelpScoutError>;
+
+    /// Every article in a collection, in full so slugs and text can be
+    /// compared.
+    fn articles(&self, collection_id: &str) -> Result<Vec<Article>, HelpScoutError>;
+
+    fn create(&self, article: &NewArticle) -> Result<Article, HelpScoutError>;
+
+    fn update(&self, id: &str, article: &NewArticle) -> Result<Article, HelpScoutError>;
+}
+
+impl Remote for DocsClient {
+    fn collections(&self, site_id: &str) -> Result<Vec<DocsCollection>, HelpScoutError> {
+        all_pages(|page| super::collections().site_id(site_id).page(page).list(self))
+    }
+
+    fn categories(&self, collection_id: &str) -> Result<Vec<Category>, HelpScoutError> {
+        all_pages(|page| super::categories().page(page).list(self, collection_id))
+    }
+
+    // The list endpoint doesn't include slugs, so fetch each article.
+    fn articles(&self, collection_id: &str) -> Result<Vec<Article>, HelpScoutError> {
+        let refs = all_pages(|page| super::articles().page(page).list_by_collection(self, collection_id))?;
+        refs.iter().map(|r| articles::get(self, &r.id)).collect()
+    }
+
+    fn create(&self, article: &NewArticle) -> Result<Article, HelpScoutError> {
+        article.send(self)
+    }
+
+    fn update(&self, id: &str, article: &NewArticle) -> Result<Article, HelpScoutError> {
+        article.update(self, id)
+    }
+}
+
+/// A single change the plan will make.
+#[derive(Debug, Clone)]
+pub enum Action {
+    Create {
+        article: NewArticle,
+        source: PathBuf,
+    },
+    Update {
+        id: String,
+        article: NewArticle,
+        source: PathBuf,
+        changes: Vec<&'static str>,
+    },
+    Unpublish {
+        id: String,
+        article: NewArticle,
+    },
+    Unchanged {
+        id: String,
+        slug: String,
+    },
+}
+
+/// The outcome of comparing local files with the site.
+#[derive(Debug, Clone)]
+pub struct Plan {
+    pub actions: Vec<Action>,
+}
+
+impl Plan {
+    pub fn is_empty(&self) -> bool {
+        self.actions.iter().all(|a| match *a {
+            Action::Unchanged { .. } => true,
+            _ => false,
+        })
+    }
+
+    /// Carry out the plan, returning the articles that were written.
+    pub fn apply<R: Remote>(&self, remote: &R) -> Result<Vec<Article>, HelpScoutError> {
+        let mut written = Vec::new();
+        for action in &self.actions {
+            match *action {
+                Action::Create { ref article, .. } => written.push(remote.create(article)?),
+                Action::Update { ref id, ref article, .. } => written.push(remote.update(id, article)?),
+                Action::Unpublish { ref id, ref article } => written.push(remote.update(id, article)?),
+                Action::Unchanged { .. } => {},
+            }
+        }
+        Ok(written)
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (mut created, mut updated, mut unpublished, mut unchanged) = (0, 0, 0, 0);
+        for action in &self.actions {
+            match *action {
+                Action::Create { ref article, ref source } => {
+                    created += 1;
+                    writeln!(f, "create     {:<40} {}", slug_of(article), source.display())?;
+                },
+                Action::Update { ref article, ref source, ref changes, .. } => {
+                    updated += 1;
+                    writeln!(f, "update     {:<40} {} ({})", slug_of(article), source.display(), changes.join(", "))?;
+                },
+                Action::Unpublish { ref article, .. } => {
+                    unpublished += 1;
+                    writeln!(f, "unpublish  {:<40}", slug_of(article))?;
+                },
+                Action::Unchanged { .. } => unchanged += 1,
+            }
+        }
+        write!(f, "{} to create, {} to update, {} to unpublish, {} unchanged", created, updated, unpublished, unchanged)
+    }
+}
+
+fn slug_of(article: &NewArticle) -> &str {
+    article.slug.as_ref().map(|s| s.as_ref()).unwrap_or("")
+}
+
+/// Compare `articles` with the articles already in the site's collections.
+///
+/// Only collections named by at least one file are considered managed; other
+/// collections on the site are left alone. Two files with the same slug in
+/// one collection are an error.
+pub fn plan<R: Remote>(remote: &R, site_id: &str, articles: Vec<MarkdownArticle>) -> Result<Plan, HelpScoutError> {
+    let collections = remote.collections(site_id)?;
+
+    // Resolve collections and categories named in front matter.
+    let mut managed: HashMap<String, Vec<Category>> = HashMap::new();
+    let mut resolved = Vec::with_capacity(articles.len());
+    let mut seen: HashMap<(String, String), PathBuf> = HashMap::new();
+    for article in articles {
+        let collection = find_collection(&collections, &article.front_matter.collection).ok_or_else(|| {
+            HelpScoutError::InputParseError(format!("{}: unknown collection {}", article.path.display(), article.front_matter.collection))
+        })?;
+        if !managed.contains_key(&collection.id) {
+            let categories = remote.categories(&collection.id)?;
+            managed.insert(collection.id.clone(), categories);
+        }
+        let category_id = match article.front_matter.category {
+            Some(ref name) => {
+                let category = find_category(&managed[&collection.id], name).ok_or_else(|| {
+                    HelpScoutError::InputParseError(format!("{}: unknown category {}", article.path.display(), name))
+                })?;
+                Some(category.id.clone())
+            },
+            None => None,
+        };
+        let key = (collection.id.clone(), article.slug.clone());
+        if let Some(first) = seen.get(&key) {
+            return Err(HelpScoutError::InputParseError(format!("{}: slug {} is already used by {}", article.path.display(), article.slug, first.display())));
+        }
+        seen.insert(key, article.path.clone());
+        resolved.push((article, collection.id.clone(), category_id));
+    }
+
+    let mut existing: HashMap<(String, String), Article> = HashMap::new();
+    for collection_id in managed.keys() {
+        for article in remote.articles(collection_id)? {
+            existing.insert((collection_id.clone(), article.slug.clone()), article);
+        }
+    }
+
+    let mut actions = Vec::new();
+    for (local, collection_id, category_id) in resolved {
+        let mut new = NewArticle::new(&collection_id, &local.front_matter.title, &local.html);
+        new.slug(&local.slug).status(local.status()).keywords(local.front_matter.keywords.clone());
+        if let Some(category_id) = category_id {
+            new.categories(vec![category_id]);
+        }
+
+        match existing.get(&(collection_id, local.slug.clone())) {
+            Some(remote) => {
+                let changes = differences(remote, &new);
+                if changes.is_empty() {
+                    actions.push(Action::Unchanged { id: remote.id.clone(), slug: local.slug.clone() });
+                } else {
+                    actions.push(Action::Update { id: remote.id.clone(), article: new, source: local.path.clone(), changes: changes });
+                }
+            },
+            None => actions.push(Action::Create { article: new, source: local.path.clone() }),
+        }
+    }
+
+    let mut orphans: Vec<&Article> = existing.iter()
+        .filter(|&(key, article)| !seen.contains_key(key) && article.status == ArticleStatus::Published)
+        .map(|(_, article)| article)
+        .collect();
+    orphans.sort_by(|a, b| a.slug.cmp(&b.slug));
+    for remote in orphans {
+        let mut article = NewArticle::new(&remote.collection_id, &remote.name, &remote.text);
+        article.slug(&remote.slug).status(ArticleStatus::NotPublished).categories(remote.categories.clone());
+        actions.push(Action::Unpublish { id: remote.id.clone(), article: article });
+    }
+
+    Ok(Plan { actions: actions })
+}
+
+fn differences(remote: &Article, local: &NewArticle) -> Vec<&'static str> {
+    let mut changes = Vec::new();
+    if remote.name != local.name {
+        changes.push("title");
+    }
+    if remote.text.trim() != local.text.trim() {
+        changes.push("text");
+    }
+    if Some(&remote.status) != local.status.as_ref() {
+        changes.push("status");
+    }
+    if let Some(ref categories) = local.categories {
+        if !categories.iter().all(|c| remote.categories.contains(c)) {
+            changes.push("category");
+        }
+    }
+    let remote_keywords = remote.keywords.clone().unwrap_or_default();
+    if local.keywords.as_ref().map_or(false, |k| *k != remote_keywords) {
+        changes.push("keywords");
+    }
+    changes
+}
+
+fn find_collection<'a>(collections: &'a [DocsCollection], name: &str) -> Option<&'a DocsCollection> {
+    collections.iter().find(|c| c.id == name || c.slug == name || c.name == name)
+}
+
+fn find_category<'a>(categories: &'a [Category], name: &str) -> Option<&'a Category> {
+    categories.iter().find(|c| c.id == name || c.slug == name || c.name == name)
+}
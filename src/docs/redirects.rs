@@ -0,0 +1,110 @@
+//! Docs Redirect Endpoints
+//!
+//! - [List](fn.list.html)
+//! - [Get](fn.get.html)
+//! - [Find](fn.find.html)
+//! - [Create](struct.NewRedirect.html#method.send)
+//! - [Update](struct.NewRedirect.html#method.update)
+//! - [Delete](fn.delete.html)
+use serde_json;
+
+use error::HelpScoutError;
+use envelope::{Collection, Page};
+use super::{DocsClient, RELOAD, unwrap, unwrap_collection};
+
+api_enum! {
+    /// What a redirect points at.
+    pub enum RedirectType {
+        Article => "article",
+        Category => "category",
+        Collection => "collection",
+        Url => "url",
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Redirect {
+    pub id: String,
+    pub site_id: String,
+    pub url_mapping: String,
+    pub redirect: String,
+    #[serde(rename = "type")]
+    pub redirect_type: Option<RedirectType>,
+}
+
+/// List Redirects for a site
+///
+/// API docs: <https://developer.helpscout.com/docs-api/redirects/list/>
+pub fn list(client: &DocsClient, site_id: &str, page: Option<i32>) -> Result<Collection<Redirect>, HelpScoutError> {
+    let res = client.get(&format!("redirects/site/{}", site_id), Page { page: page })?;
+    unwrap_collection(res, "redirects")
+}
+
+/// Get Redirect
+///
+/// API docs: <https://developer.helpscout.com/docs-api/redirects/get/>
+pub fn get(client: &DocsClient, id: &str) -> Result<Redirect, HelpScoutError> {
+    let res = client.get(&format!("redirects/{}", id), ())?;
+    unwrap(res, "redirect")
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FindParams<'a> {
+    url: &'a str,
+    site_id: &'a str,
+}
+
+/// Find the redirect for a URL on a site
+///
+/// API docs: <https://developer.helpscout.com/docs-api/redirects/find/>
+pub fn find(client: &DocsClient, site_id: &str, url: &str) -> Result<Redirect, HelpScoutError> {
+    let res = client.get("redirects", FindParams { url: url, site_id: site_id })?;
+    unwrap(res, "redirect")
+}
+
+/// Delete Redirect
+///
+/// API docs: <https://developer.helpscout.com/docs-api/redirects/delete/>
+pub fn delete(client: &DocsClient, id: &str) -> Result<(), HelpScoutError> {
+    client.delete(&format!("redirects/{}", id), ())?;
+    Ok(())
+}
+
+/// Body for creating or updating a redirect.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewRedirect {
+    pub site_id: String,
+    pub url_mapping: String,
+    pub redirect: String,
+}
+
+impl NewRedirect {
+    pub fn new(site_id: &str, url_mapping: &str, redirect: &str) -> NewRedirect {
+        NewRedirect {
+            site_id: site_id.into(),
+            url_mapping: url_mapping.into(),
+            redirect: redirect.into(),
+        }
+    }
+
+    /// Create Redirect
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/redirects/create/>
+    pub fn send(&self, client: &DocsClient) -> Result<Redirect, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.post("redirects", RELOAD, Some(body.to_string()))?;
+        unwrap(res, "redirect")
+    }
+
+    /// Update Redirect
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/redirects/update/>
+    pub fn update(&self, client: &DocsClient, id: &str) -> Result<Redirect, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.put(&format!("redirects/{}", id), RELOAD, Some(body.to_string()))?;
+        unwrap(res, "redirect")
+    }
+}
@@ -0,0 +1,186 @@
+//! Docs Site Endpoints
+//!
+//! - [List](struct.SitesBuilder.html#method.list)
+//! - [Get](fn.get.html)
+//! - [Create](struct.NewSite.html#method.send)
+//! - [Update](struct.NewSite.html#method.update)
+//! - [Delete](fn.delete.html)
+use serde_json;
+use chrono::{DateTime, Utc};
+
+use error::HelpScoutError;
+use envelope::Collection;
+use id::{MailboxId, UserId};
+use super::{DocsClient, RELOAD, unwrap, unwrap_collection};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Site {
+    pub id: String,
+    pub status: String,
+    pub sub_domain: String,
+    pub cname: Option<String>,
+    pub has_public_site: bool,
+    pub company_name: String,
+    pub title: Option<String>,
+    pub logo_url: Option<String>,
+    pub logo_width: Option<i32>,
+    pub logo_height: Option<i32>,
+    pub fav_icon_url: Option<String>,
+    pub touch_icon_url: Option<String>,
+    pub home_url: Option<String>,
+    pub home_link_text: Option<String>,
+    pub bg_color: Option<String>,
+    pub description: Option<String>,
+    pub has_contact_form: bool,
+    pub mailbox_id: Option<MailboxId>,
+    pub contact_email: Option<String>,
+    pub style_sheet_url: Option<String>,
+    pub header_code: Option<String>,
+    pub created_by: UserId,
+    pub updated_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SitesBuilder {
+    pub(crate) page: Option<i32>,
+}
+
+impl SitesBuilder {
+    /// Set the page for list actions
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// List Sites
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/sites/list/>
+    pub fn list(self, client: &DocsClient) -> Result<Collection<Site>, HelpScoutError> {
+        let res = client.get("sites", self)?;
+        unwrap_collection(res, "sites")
+    }
+}
+
+/// Get Site
+///
+/// API docs: <https://developer.helpscout.com/docs-api/sites/get/>
+pub fn get(client: &DocsClient, id: &str) -> Result<Site, HelpScoutError> {
+    let res = client.get(&format!("sites/{}", id), ())?;
+    unwrap(res, "site")
+}
+
+/// Delete Site
+///
+/// API docs: <https://developer.helpscout.com/docs-api/sites/delete/>
+pub fn delete(client: &DocsClient, id: &str) -> Result<(), HelpScoutError> {
+    client.delete(&format!("sites/{}", id), ())?;
+    Ok(())
+}
+
+/// Body for creating or updating a site. Fields left unset are not sent,
+/// so an update keeps their current values.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSite {
+    pub sub_domain: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_public_site: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fav_icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub touch_icon_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_link_text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bg_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub has_contact_form: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mailbox_id: Option<MailboxId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style_sheet_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header_code: Option<String>,
+}
+
+impl NewSite {
+    pub fn new(sub_domain: &str, title: &str) -> NewSite {
+        NewSite {
+            sub_domain: sub_domain.into(),
+            title: title.into(),
+            cname: None,
+            has_public_site: None,
+            logo_url: None,
+            fav_icon_url: None,
+            touch_icon_url: None,
+            home_url: None,
+            home_link_text: None,
+            bg_color: None,
+            description: None,
+            has_contact_form: None,
+            mailbox_id: None,
+            contact_email: None,
+            style_sheet_url: None,
+            header_code: None,
+        }
+    }
+
+    pub fn cname(&mut self, cname: &str) -> &mut NewSite {
+        self.cname = Some(cname.into());
+        self
+    }
+
+    pub fn has_public_site(&mut self, has_public_site: bool) -> &mut NewSite {
+        self.has_public_site = Some(has_public_site);
+        self
+    }
+
+    pub fn home_url(&mut self, home_url: &str) -> &mut NewSite {
+        self.home_url = Some(home_url.into());
+        self
+    }
+
+    pub fn description(&mut self, description: &str) -> &mut NewSite {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn contact_form(&mut self, mailbox_id: MailboxId, contact_email: &str) -> &mut NewSite {
+        self.has_contact_form = Some(true);
+        self.mailbox_id = Some(mailbox_id);
+        self.contact_email = Some(contact_email.into());
+        self
+    }
+
+    /// Create Site
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/sites/create/>
+    pub fn send(&self, client: &DocsClient) -> Result<Site, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.post("sites", RELOAD, Some(body.to_string()))?;
+        unwrap(res, "site")
+    }
+
+    /// Update Site
+    ///
+    /// API docs: <https://developer.helpscout.com/docs-api/sites/update/>
+    pub fn update(&self, client: &DocsClient, id: &str) -> Result<Site, HelpScoutError> {
+        let body = serde_json::to_value(self)?;
+        let res = client.put(&format!("sites/{}", id), RELOAD, Some(body.to_string()))?;
+        unwrap(res, "site")
+    }
+}
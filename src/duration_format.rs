@@ -1,27 +1,46 @@
-
+/// Report time metrics, which HelpScout sends as a number of seconds.
+///
+/// The number can be an integer or a float, and may be missing, null, a
+/// numeric string, or negative (e.g. -1) when there's nothing to measure.
+/// All of those that don't hold a real duration become `None`.
 pub mod optional_duration_format {
-    use time::Duration;
-    use serde::{Deserialize, Deserializer};
+    use std::time::Duration;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use serde::de::Error;
     use serde_json::Value;
 
-    // TODO: Rework this implementation
+    // Written back out as (fractional) seconds, the way HelpScout sends them
+    pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match *duration {
+            Some(d) => serializer.serialize_f64(d.as_secs() as f64 + d.subsec_nanos() as f64 / 1e9),
+            None => serializer.serialize_none(),
+        }
+    }
+
     pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
         where D: Deserializer<'de>
     {
-        let v = Value::deserialize(deserializer)?;
+        let seconds = match Value::deserialize(deserializer)? {
+            Value::Null => return Ok(None),
+            Value::Number(n) => n.as_f64(),
+            Value::String(s) => {
+                if s.trim().is_empty() {
+                    return Ok(None);
+                }
+                Some(s.trim().parse::<f64>().map_err(|_| D::Error::custom(format!("invalid duration: {:?}", s)))?)
+            },
+            other => return Err(D::Error::custom(format!("invalid duration: {}", other))),
+        };
 
-        if v.is_null() {
-            return Ok(None)
-        }
+        Ok(seconds.and_then(from_seconds))
+    }
 
-        match v.as_f64() {
-            Some(f) => {
-                let i = (f*1000.0).round() as i64;
-                Ok(Some(Duration::milliseconds(i)))
-            },
-            None => {
-                Ok(None)
-            }
+    pub(crate) fn from_seconds(seconds: f64) -> Option<Duration> {
+        if !seconds.is_finite() || seconds < 0.0 {
+            return None;
         }
+        Some(Duration::from_millis((seconds * 1000.0).round() as u64))
     }
 }
@@ -1,9 +1,95 @@
+use serde::de::DeserializeOwned;
+use serde_json::{self, Value};
+
+use client::Client;
+use error::HelpScoutError;
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Collection<T> {
     pub page: i32,
     pub pages: i32,
     pub count: i32,
     pub items: Vec<T>,
+
+    /// Items that failed to deserialize. Only populated when the client was
+    /// configured with `lenient_collections`, otherwise a bad item fails the
+    /// whole page.
+    #[serde(skip)]
+    pub skipped: Vec<SkippedItem>,
+}
+
+/// An item left out of a leniently parsed `Collection`.
+#[derive(Debug, Clone)]
+pub struct SkippedItem {
+    /// Position of the item in the page as returned by HelpScout.
+    pub index: usize,
+    pub value: Value,
+    pub error: HelpScoutError,
+}
+
+impl<T> Collection<T> where T: DeserializeOwned {
+    /// Parse a collection item by item, keeping the items that deserialize
+    /// and recording the rest in `skipped`.
+    ///
+    /// The envelope itself (`page`, `pages`, `count`) must still be valid.
+    pub fn from_value_lenient(value: Value) -> Result<Collection<T>, HelpScoutError> {
+        let raw: Collection<Value> = serde_json::from_value(value)?;
+        let mut items = Vec::with_capacity(raw.items.len());
+        let mut skipped = Vec::new();
+
+        for (index, item) in raw.items.into_iter().enumerate() {
+            match serde_json::from_value(item.clone()) {
+                Ok(parsed) => items.push(parsed),
+                Err(e) => {
+                    warn!("Skipping collection item {}: {}", index, e);
+                    skipped.push(SkippedItem {
+                        index: index,
+                        value: item,
+                        error: e.into(),
+                    });
+                },
+            }
+        }
+
+        Ok(Collection {
+            page: raw.page,
+            pages: raw.pages,
+            count: raw.count,
+            items: items,
+            skipped: skipped,
+        })
+    }
+
+    pub(crate) fn from_response(client: &Client, value: Value) -> Result<Collection<T>, HelpScoutError> {
+        if client.lenient_collections {
+            Collection::from_value_lenient(value)
+        } else {
+            Ok(serde_json::from_value(value)?)
+        }
+    }
+}
+
+/// Query string for list endpoints that only take a page number
+#[derive(Debug, Serialize)]
+pub(crate) struct Page {
+    pub page: Option<i32>,
+}
+
+/// Fetch every page of a list endpoint. `list` is called with page numbers
+/// from 1 until the last page HelpScout reports.
+pub(crate) fn all_pages<T, F>(mut list: F) -> Result<Vec<T>, HelpScoutError>
+    where F: FnMut(i32) -> Result<Collection<T>, HelpScoutError>
+{
+    let mut items = Vec::new();
+    let mut page = 1;
+    loop {
+        let collection = list(page)?;
+        items.extend(collection.items);
+        if page >= collection.pages {
+            return Ok(items);
+        }
+        page += 1;
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -6,7 +6,7 @@ use reqwest;
 use serde_json;
 use serde_url_params;
 
-use client::Status;
+use client::{Status, FieldError};
 
 /// The error type used by this library.
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -49,6 +49,26 @@ pub enum HelpScoutError {
 
     /// The server gave an invalid response.
     InvalidServerResponse,
+
+    /// The request body failed client side validation and was not sent.
+    ValidationError(Vec<FieldError>),
+
+    /// A local input, such as a file to import or publish, could not be parsed.
+    InputParseError(String),
+
+    /// The local database, such as the sync mirror, returned an error.
+    DatabaseError(String),
+}
+
+impl HelpScoutError {
+    /// The per-field errors attached to a `BadRequest` or `ValidationError`, if any.
+    pub fn field_errors(&self) -> &[FieldError] {
+        match *self {
+            HelpScoutError::BadRequest(ref s) => &s.validation_errors,
+            HelpScoutError::ValidationError(ref errors) => errors,
+            _ => &[],
+        }
+    }
 }
 
 impl error::Error for HelpScoutError {
@@ -68,6 +88,9 @@ impl error::Error for HelpScoutError {
             RequestUrlError(_) => "Request URL error",
             RequestUrlEncodeError(_) => "Request URL Encode error",
             InvalidServerResponse => "Invalid server response",
+            ValidationError(_) => "Validation error",
+            InputParseError(_) => "Input parse error",
+            DatabaseError(_) => "Database error",
         }
     }
     fn cause(&self) -> Option<&error::Error> {
@@ -80,7 +103,13 @@ impl fmt::Display for HelpScoutError {
         use HelpScoutError::*;
 
         match *self {
-            BadRequest(ref s) => write!(f, "Bad Request: {}", s.error),
+            BadRequest(ref s) => {
+                write!(f, "Bad Request: {}", s.error)?;
+                for e in &s.validation_errors {
+                    write!(f, "; {}", e)?;
+                }
+                Ok(())
+            },
             UnauthorizedKey(ref s) => write!(f, "Unauthorized API Key: {}", s.error),
             Forbidden(ref s) => write!(f, "Forbidden: {}", s.error),
             UserNotFound(ref s) => write!(f, "User Not Found: {}", s.error),
@@ -93,6 +122,15 @@ impl fmt::Display for HelpScoutError {
             RequestUrlError(ref s) => write!(f, "Bad Request URL: {}", s),
             RequestUrlEncodeError(ref s) => write!(f, "Bad Request URL Encoding: {}", s),
             InvalidServerResponse => write!(f, "Server returned an invalid response"),
+            ValidationError(ref errors) => {
+                write!(f, "Validation error")?;
+                for e in errors {
+                    write!(f, "; {}", e)?;
+                }
+                Ok(())
+            },
+            InputParseError(ref s) => write!(f, "Input parse error: {}", s),
+            DatabaseError(ref s) => write!(f, "Database error: {}", s),
         }
     }
 }
@@ -126,3 +164,10 @@ impl From<io::Error> for HelpScoutError {
         HelpScoutError::IoError(e.to_string())
     }
 }
+
+#[cfg(feature = "sync")]
+impl From<::rusqlite::Error> for HelpScoutError {
+    fn from(e: ::rusqlite::Error) -> Self {
+        HelpScoutError::DatabaseError(e.to_string())
+    }
+}
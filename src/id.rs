@@ -0,0 +1,44 @@
+//! Typed identifiers for HelpScout resources.
+//!
+//! All ids are 64 bit; conversation ids in particular have outgrown `i32` in
+//! large accounts.
+
+api_id! {
+    /// Identifies a mailbox.
+    pub struct MailboxId;
+}
+
+api_id! {
+    /// Identifies a folder within a mailbox.
+    pub struct FolderId;
+}
+
+api_id! {
+    /// Identifies a conversation.
+    pub struct ConversationId;
+}
+
+api_id! {
+    /// Identifies a single thread within a conversation.
+    pub struct ThreadId;
+}
+
+api_id! {
+    /// Identifies a customer.
+    pub struct CustomerId;
+}
+
+api_id! {
+    /// Identifies a user.
+    pub struct UserId;
+}
+
+api_id! {
+    /// Identifies a team.
+    pub struct TeamId;
+}
+
+api_id! {
+    /// Identifies an attachment.
+    pub struct AttachmentId;
+}
@@ -5,7 +5,9 @@
 //! - [Developer Docs](https://developer.helpscout.com/)
 //! - [Help Desk API](https://developer.helpscout.com/help-desk-api/)
 //!
-//! Note: the api module implements the Help Desk API.
+//! Note: the api module implements the Help Desk API. The
+//! [docs](docs/index.html) module implements the Docs API, which has its own
+//! client.
 //!
 //! ## Usage
 //!
@@ -26,7 +28,7 @@
 //!     let client = Client::new(&api_key);
 //!
 //!     // Used the mailbox list endpoint for test. Use whatever you need here.
-//!     let mailboxes = mailboxes::list(&client);
+//!     let mailboxes = mailboxes::list(&client, None);
 //!
 //!     assert!(mailboxes.items.len() > 0);
 //! }
@@ -53,7 +55,7 @@ extern crate serde_json;
 extern crate serde_url_params;
 
 extern crate chrono;
-extern crate time;
+extern crate crossbeam;
 
 #[macro_use]
 extern crate log;
@@ -64,17 +66,39 @@ extern crate dotenv;
 // Make this enabled by webhook feature
 extern crate ring;
 
+#[cfg(feature = "publish")]
+extern crate pulldown_cmark;
+#[cfg(feature = "publish")]
+extern crate serde_yaml;
+#[cfg(feature = "sync")]
+#[macro_use]
+extern crate rusqlite;
+
+#[macro_use]
+mod macros;
+
 mod error;
 pub use error::HelpScoutError;
 
 mod client;
-pub use client::{Client, Status};
+pub use client::{Client, Status, FieldError};
+
+mod id;
+pub use id::{MailboxId, FolderId, ConversationId, ThreadId, CustomerId, UserId, TeamId, AttachmentId};
 
 mod envelope;
-pub use envelope::{Collection, Item};
+pub use envelope::{Collection, Item, SkippedItem};
+
+mod validate;
 
 mod date_format;
 mod duration_format;
 
 pub mod api;
+pub mod calendar;
+pub mod docs;
+pub mod mail;
+pub mod sla;
+#[cfg(feature = "sync")]
+pub mod sync;
 pub mod webhook;
@@ -0,0 +1,128 @@
+/// Declare an enum that maps to a fixed set of HelpScout string values.
+///
+/// HelpScout adds new values to its enums without notice, so every enum
+/// declared here gets an extra `Unknown(String)` variant. Values that aren't
+/// listed deserialize into it instead of failing, and serialize back out
+/// unchanged.
+///
+/// ```rust,ignore
+/// api_enum! {
+///     pub enum ConversationType {
+///         Email => "email",
+///         Chat => "chat",
+///     }
+/// }
+/// ```
+macro_rules! api_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $( $(#[$vmeta:meta])* $variant:ident => $value:tt, )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $( $(#[$vmeta])* $variant, )*
+            /// A value not known to this version of the library.
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value HelpScout uses on the wire.
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $( $name::$variant => $value, )*
+                    $name::Unknown(ref s) => s,
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(s: &'a str) -> $name {
+                match s {
+                    $( $value => $name::$variant, )*
+                    other => $name::Unknown(other.into()),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                let s = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                Ok($name::from(s.as_str()))
+            }
+        }
+    };
+}
+
+/// Declare a typed identifier wrapping an `i64`.
+///
+/// Serializes as a bare number so it can be used anywhere HelpScout expects
+/// an id, while keeping one kind of id from being passed as another.
+macro_rules! api_id {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident;
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(pub i64);
+
+        impl From<i64> for $name {
+            fn from(id: i64) -> $name {
+                $name(id)
+            }
+        }
+
+        impl From<i32> for $name {
+            fn from(id: i32) -> $name {
+                $name(id.into())
+            }
+        }
+
+        impl From<$name> for i64 {
+            fn from(id: $name) -> i64 {
+                id.0
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                serializer.serialize_i64(self.0)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<$name, D::Error>
+                where D: ::serde::Deserializer<'de>
+            {
+                <i64 as ::serde::Deserialize>::deserialize(deserializer).map($name)
+            }
+        }
+    };
+}
@@ -0,0 +1,250 @@
+//! Mailbox Export
+//!
+//! Fetches every conversation in a mailbox, or every conversation a customer
+//! has had, with its threads and attachments, and writes it out as email.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! extern crate helpscout;
+//!
+//! use std::fs::File;
+//!
+//! use helpscout::{CustomerId, MailboxId};
+//! use helpscout::mail::export::MailExport;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!
+//!     // Everything in one mailbox, as one mbox file
+//!     let mbox = File::create("support.mbox").expect("create mbox");
+//!     let summary = MailExport::mailbox(&client, MailboxId(1234))
+//!         .write_mbox(mbox)
+//!         .expect("export mailbox");
+//!     println!("{:?}", summary);
+//!
+//!     // One customer's conversations, as a .eml file per thread
+//!     MailExport::customer(&client, CustomerId(29418))
+//!         .domain("support.example.com")
+//!         .write_eml("hold-29418")
+//!         .expect("export customer");
+//! }
+//! ```
+//!
+//! Notes are exported along with customer and user replies, marked with an
+//! `X-HelpScout-Thread-Type: note` header. Line items (status changes and
+//! the like) are left out.
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use api::conversations::{self, Conversation, ConversationListParamBuilder, ConversationThread, ConversationThreadType};
+use api::mailboxes;
+use client::Client;
+use envelope::all_pages;
+use error::HelpScoutError;
+use id::{AttachmentId, ConversationId, CustomerId, MailboxId, ThreadId};
+use super::{Message, MessageAttachment, conversation_message_id, person_address, thread_message_id};
+
+/// What an export wrote.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportSummary {
+    pub conversations: usize,
+    pub messages: usize,
+    pub attachments: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Scope {
+    Mailbox(MailboxId),
+    Customer(CustomerId),
+}
+
+pub struct MailExport<'a> {
+    client: &'a Client,
+    scope: Scope,
+    domain: String,
+    attachments: bool,
+}
+
+impl<'a> MailExport<'a> {
+    /// Every conversation in a mailbox
+    pub fn mailbox(client: &'a Client, mailbox: MailboxId) -> MailExport<'a> {
+        MailExport::new(client, Scope::Mailbox(mailbox))
+    }
+
+    /// Every conversation with a customer, across all mailboxes
+    pub fn customer(client: &'a Client, customer: CustomerId) -> MailExport<'a> {
+        MailExport::new(client, Scope::Customer(customer))
+    }
+
+    fn new(client: &'a Client, scope: Scope) -> MailExport<'a> {
+        MailExport {
+            client: client,
+            scope: scope,
+            domain: "helpscout.net".into(),
+            attachments: true,
+        }
+    }
+
+    /// Domain for the generated Message-IDs
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = domain.into();
+        self
+    }
+
+    /// Whether to download and include attachments, on unless set
+    pub fn attachments(mut self, attachments: bool) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
+    /// Write every message into one mbox
+    pub fn write_mbox<W: Write>(&self, out: W) -> Result<ExportSummary, HelpScoutError> {
+        let mut out = BufWriter::new(out);
+        let summary = self.export(|_, message| {
+            message.write_mbox(&mut out)?;
+            Ok(())
+        })?;
+        out.flush()?;
+        Ok(summary)
+    }
+
+    /// Write a `<conversation number>-<thread id>.eml` file per message into
+    /// `dir`, creating it if needed
+    pub fn write_eml<P: AsRef<Path>>(&self, dir: P) -> Result<ExportSummary, HelpScoutError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        self.export(|name, message| {
+            let mut file = File::create(dir.join(name))?;
+            file.write_all(message.to_rfc5322().as_bytes())?;
+            Ok(())
+        })
+    }
+
+    fn export<F>(&self, mut write: F) -> Result<ExportSummary, HelpScoutError>
+        where F: FnMut(&str, &Message) -> Result<(), HelpScoutError>
+    {
+        let mut summary = ExportSummary::default();
+        let mut mailbox_emails = HashMap::new();
+
+        for (mailbox, id) in self.conversation_ids()? {
+            let conversation = conversations::get(self.client, id)?.item;
+
+            if !mailbox_emails.contains_key(&mailbox) {
+                let email = mailboxes::get(self.client, mailbox)?.item.email;
+                mailbox_emails.insert(mailbox, email);
+            }
+
+            let mut attachments = HashMap::new();
+            if self.attachments {
+                for thread in conversation.threads.iter().flat_map(|t| t.iter()) {
+                    for attachment in thread.attachments.iter().flat_map(|a| a.iter()) {
+                        let data = conversations::get_attachment_data(self.client, attachment.id)?.item;
+                        attachments.insert(attachment.id, data.data);
+                    }
+                }
+            }
+
+            let messages = conversation_messages(&conversation, &mailbox_emails[&mailbox], &self.domain, &attachments);
+            for (thread, message) in &messages {
+                let name = format!("{}-{}.eml", conversation.number, thread);
+                write(&name, message)?;
+                summary.messages += 1;
+                summary.attachments += message.attachments.len();
+            }
+            summary.conversations += 1;
+        }
+        Ok(summary)
+    }
+
+    fn conversation_ids(&self) -> Result<Vec<(MailboxId, ConversationId)>, HelpScoutError> {
+        let client = self.client;
+        let mailboxes = match self.scope {
+            Scope::Mailbox(mailbox) => vec![mailbox],
+            Scope::Customer(_) => all_pages(|page| mailboxes::list(client, Some(page)))?.iter().map(|m| m.id).collect(),
+        };
+
+        let mut ids = Vec::new();
+        for mailbox in mailboxes {
+            let conversations = all_pages(|page| {
+                let mut params = ConversationListParamBuilder::new();
+                params.page(page);
+                match self.scope {
+                    Scope::Mailbox(_) => conversations::list(client, mailbox, &mut params),
+                    Scope::Customer(customer) => conversations::list_by_customer(client, mailbox, customer, &mut params),
+                }
+            })?;
+            ids.extend(conversations.iter().map(|c| (mailbox, c.id)));
+        }
+        Ok(ids)
+    }
+}
+
+/// A conversation's threads as messages, oldest first, keyed by thread id.
+///
+/// `mailbox_email` is who customers wrote to. `attachments` holds the base64
+/// data for each attachment to include; any not in it are left out.
+pub fn conversation_messages(
+    conversation: &Conversation,
+    mailbox_email: &str,
+    domain: &str,
+    attachments: &HashMap<AttachmentId, String>,
+) -> Vec<(ThreadId, Message)> {
+    let mut threads: Vec<&ConversationThread> = conversation.threads.iter()
+        .flat_map(|t| t.iter())
+        .filter(|t| t.conversation_thread_type != ConversationThreadType::LineItem)
+        .collect();
+    threads.sort_by_key(|t| (t.created_at, t.id));
+
+    let root = conversation_message_id(conversation.id, domain);
+    let subject = conversation.subject.clone().unwrap_or_default();
+    let customer = person_address(&conversation.customer).unwrap_or_default();
+    let mut references = vec![root.clone()];
+
+    threads.iter().enumerate().map(|(i, thread)| {
+        let mut message = Message::default();
+        let id = thread_message_id(conversation.id, thread.id, domain);
+        let author = thread.created_by.as_ref().and_then(person_address);
+
+        let (from, to) = if thread.created_by_customer {
+            (author.unwrap_or_else(|| customer.clone()), join(&thread.to).unwrap_or_else(|| mailbox_email.to_string()))
+        } else {
+            (author.unwrap_or_else(|| mailbox_email.to_string()), join(&thread.to).unwrap_or_else(|| customer.clone()))
+        };
+
+        message.push_header("From", from);
+        message.push_header("To", to);
+        message.push_header("Cc", join(&thread.cc).unwrap_or_default());
+        message.push_header("Bcc", join(&thread.bcc).unwrap_or_default());
+        message.push_header("Subject", if i == 0 { subject.clone() } else { format!("Re: {}", subject) });
+        message.push_header("Date", thread.created_at.to_rfc2822());
+        message.push_header("Message-ID", id.clone());
+        message.push_header("In-Reply-To", references.last().cloned().unwrap_or_default());
+        message.push_header("References", references.join(" "));
+        message.push_header("X-HelpScout-Conversation", format!("{} #{}", conversation.id, conversation.number));
+        message.push_header("X-HelpScout-Thread-Type", thread.conversation_thread_type.as_str());
+        references.push(id);
+
+        message.body = thread.body.clone().unwrap_or_default();
+        message.html = true;
+        message.attachments = thread.attachments.iter()
+            .flat_map(|a| a.iter())
+            .filter_map(|a| attachments.get(&a.id).map(|data| MessageAttachment {
+                file_name: a.file_name.clone(),
+                mime_type: a.mime_type.clone(),
+                data: data.clone(),
+            }))
+            .collect();
+
+        (thread.id, message)
+    }).collect()
+}
+
+fn join(addresses: &Option<Vec<String>>) -> Option<String> {
+    match *addresses {
+        Some(ref a) if !a.is_empty() => Some(a.join(", ")),
+        _ => None,
+    }
+}
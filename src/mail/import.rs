@@ -0,0 +1,475 @@
+//! Mailbox Import
+//!
+//! Brings old email into a mailbox, for teams moving to HelpScout from
+//! somewhere else. Messages are read from mbox or `.eml` files and grouped
+//! into conversations by their `Message-ID`, `References` and `In-Reply-To`
+//! headers.
+//!
+//! Senders are matched to users by email. Everyone else is a customer, and
+//! is created if HelpScout doesn't know them yet. Conversations are created
+//! with `imported` set, so nobody is emailed and the original dates stay.
+//!
+//! Replies sent as the mailbox address itself have no user to credit. They
+//! go to the [fallback user](struct.MailImport.html#method.fallback_user)
+//! when one is set; otherwise their conversations are skipped and listed in
+//! the [ImportSummary](struct.ImportSummary.html).
+//!
+//! ## Resuming
+//!
+//! With a [checkpoint](struct.MailImport.html#method.checkpoint) file, each
+//! conversation is noted there once it's created. Running the same import
+//! again skips those, so an import that fails part way can just be rerun.
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! extern crate helpscout;
+//!
+//! use helpscout::{MailboxId, UserId};
+//! use helpscout::mail::import::MailImport;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!
+//!     let summary = MailImport::new(&client, MailboxId(1234))
+//!         .fallback_user(UserId(4321))
+//!         .checkpoint("support.mbox.done")
+//!         .tag("imported")
+//!         .import_mbox("support.mbox")
+//!         .expect("import mailbox");
+//!     println!("{:?}", summary);
+//! }
+//! ```
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+use api;
+use api::conversations::{
+    self, ConversationCreateParamBuilder, ConversationThreadType, ConversationType, NewAttachment,
+    NewConversation, NewConversationThread,
+};
+use api::customers::{CustomerEmail, CustomerEmailLocationType, NewCustomer};
+use api::mailboxes::{self, MailboxRef};
+use api::person::{Person, PersonType};
+use client::Client;
+use envelope::all_pages;
+use error::HelpScoutError;
+use id::{CustomerId, MailboxId, UserId};
+use super::{Message, split_mbox};
+
+/// What an import did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub conversations: usize,
+    pub threads: usize,
+    pub attachments: usize,
+    pub customers_created: usize,
+    /// Conversations the checkpoint says were imported by an earlier run
+    pub already_imported: usize,
+    /// Conversations left out, by checkpoint key, and why
+    pub skipped: Vec<(String, SkipReason)>,
+}
+
+impl ImportSummary {
+    fn skip(&mut self, key: String, reason: SkipReason) {
+        warn!("Skipping conversation {}: {:?}", key, reason);
+        self.skipped.push((key, reason));
+    }
+}
+
+/// Why a group of messages can't become a conversation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// Everyone in it is staff, so nobody can be its customer
+    NoCustomer,
+    /// A customer, by email, who isn't in the directory
+    UnknownCustomer(String),
+    /// A reply sent as the mailbox address, with no fallback user to credit
+    NoUser(String),
+}
+
+/// Who's who, by lowercase email.
+#[derive(Debug, Clone, Default)]
+pub struct Directory {
+    /// The mailbox's own address, which is neither a user nor a customer
+    pub mailbox_email: String,
+    pub users: HashMap<String, UserId>,
+    pub customers: HashMap<String, CustomerId>,
+    /// Credited with replies sent as the mailbox address
+    pub fallback_user: Option<UserId>,
+}
+
+impl Directory {
+    fn is_staff(&self, email: &str) -> bool {
+        email.eq_ignore_ascii_case(&self.mailbox_email) || self.users.contains_key(email)
+    }
+
+    fn user(&self, email: &str) -> Result<Person, SkipReason> {
+        let id = match self.users.get(email) {
+            Some(&id) => id,
+            None => self.fallback_user.ok_or_else(|| SkipReason::NoUser(email.to_string()))?,
+        };
+        let mut person = Person::new(id.0);
+        person.person_type = Some(PersonType::User);
+        Ok(person)
+    }
+
+    fn customer(&self, name: &str, email: &str) -> Result<Person, SkipReason> {
+        let id = self.customers.get(email).ok_or_else(|| SkipReason::UnknownCustomer(email.to_string()))?;
+        let mut person = Person::new(id.0);
+        let (first_name, last_name) = split_name(name);
+        person.first_name = first_name;
+        person.last_name = last_name;
+        person.email = Some(email.to_string());
+        person.person_type = Some(PersonType::Customer);
+        Ok(person)
+    }
+}
+
+pub struct MailImport<'a> {
+    client: &'a Client,
+    mailbox: MailboxId,
+    checkpoint: Option<PathBuf>,
+    tags: Vec<String>,
+    fallback_user: Option<UserId>,
+}
+
+impl<'a> MailImport<'a> {
+    pub fn new(client: &'a Client, mailbox: MailboxId) -> MailImport<'a> {
+        MailImport {
+            client: client,
+            mailbox: mailbox,
+            checkpoint: None,
+            tags: Vec::new(),
+            fallback_user: None,
+        }
+    }
+
+    /// Credit replies sent as the mailbox address to this user. Without one,
+    /// conversations with such replies are skipped.
+    pub fn fallback_user(mut self, user: UserId) -> Self {
+        self.fallback_user = Some(user);
+        self
+    }
+
+    /// Note imported conversations in this file, and skip any already there
+    pub fn checkpoint<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.checkpoint = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Tag every imported conversation
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Import every message in an mbox file
+    pub fn import_mbox<P: AsRef<Path>>(&self, path: P) -> Result<ImportSummary, HelpScoutError> {
+        let messages = split_mbox(&fs::read(path)?)
+            .iter()
+            .map(|raw| Message::parse(raw))
+            .collect::<Result<Vec<Message>, HelpScoutError>>()?;
+        self.import(messages)
+    }
+
+    /// Import `.eml` files. Replies are only grouped with what they reply to
+    /// when it's in the same import.
+    pub fn import_eml<I, P>(&self, paths: I) -> Result<ImportSummary, HelpScoutError>
+        where I: IntoIterator<Item = P>, P: AsRef<Path>
+    {
+        let messages = paths.into_iter()
+            .map(|path| Message::parse(&fs::read(path)?))
+            .collect::<Result<Vec<Message>, HelpScoutError>>()?;
+        self.import(messages)
+    }
+
+    /// Import messages that have already been read
+    pub fn import(&self, messages: Vec<Message>) -> Result<ImportSummary, HelpScoutError> {
+        let client = self.client;
+        let mut summary = ImportSummary::default();
+
+        let mailbox = mailboxes::get(client, self.mailbox)?.item;
+        let mut directory = Directory::default();
+        directory.mailbox_email = mailbox.email.to_lowercase();
+        directory.fallback_user = self.fallback_user;
+        directory.users = all_pages(|page| api::users().page(page).list(client))?
+            .into_iter()
+            .map(|user| (user.email.to_lowercase(), user.id))
+            .collect();
+
+        let mut done = self.read_checkpoint()?;
+        for messages in group_conversations(messages) {
+            let key = conversation_key(&messages);
+            if done.contains(&key) {
+                summary.already_imported += 1;
+                continue;
+            }
+
+            // Don't create customers for a conversation that can't be imported
+            if let Err(reason) = check_senders(&messages, &directory) {
+                summary.skip(key, reason);
+                continue;
+            }
+            for (name, email) in customer_addresses(&messages, &directory) {
+                if !directory.customers.contains_key(&email) {
+                    if let Some(id) = self.find_or_create_customer(&name, &email, &mut summary)? {
+                        directory.customers.insert(email, id);
+                    }
+                }
+            }
+
+            let mailbox = MailboxRef { id: mailbox.id, name: mailbox.name.clone() };
+            let mut conversation = match conversation_from_messages(&messages, mailbox, &directory) {
+                Ok(conversation) => conversation,
+                Err(reason) => {
+                    summary.skip(key, reason);
+                    continue;
+                },
+            };
+            if !self.tags.is_empty() {
+                conversation.tags = Some(self.tags.clone());
+            }
+
+            // Threads line up with messages, one each
+            for (thread, message) in conversation.threads.iter_mut().zip(&messages) {
+                let mut attachments = Vec::new();
+                for attachment in &message.attachments {
+                    attachments.push(conversations::upload_attachment(client, &NewAttachment {
+                        file_name: attachment.file_name.clone(),
+                        mime_type: attachment.mime_type.clone(),
+                        data: attachment.data.clone(),
+                    })?.item);
+                }
+                if !attachments.is_empty() {
+                    summary.attachments += attachments.len();
+                    thread.attachments = Some(attachments);
+                }
+            }
+
+            let threads = conversation.threads.len();
+            let mut params = ConversationCreateParamBuilder::new(conversation);
+            params.imported(true).auto_reply(false);
+            params.send(client)?;
+
+            self.write_checkpoint(&key)?;
+            done.insert(key);
+            summary.conversations += 1;
+            summary.threads += threads;
+        }
+        Ok(summary)
+    }
+
+    fn find_or_create_customer(&self, name: &str, email: &str, summary: &mut ImportSummary) -> Result<Option<CustomerId>, HelpScoutError> {
+        if let Some(id) = self.find_customer(email)? {
+            return Ok(Some(id));
+        }
+        let (first_name, last_name) = split_name(name);
+        NewCustomer::new(
+            &first_name.unwrap_or_default(),
+            &last_name.unwrap_or_default(),
+            vec![CustomerEmail::new(email, CustomerEmailLocationType::Work)],
+        ).send(self.client)?;
+        summary.customers_created += 1;
+
+        // Creating doesn't return the id. Without it the customer is still
+        // found by email when the conversation is created.
+        self.find_customer(email)
+    }
+
+    fn find_customer(&self, email: &str) -> Result<Option<CustomerId>, HelpScoutError> {
+        let mut params = api::customers();
+        params.email(email);
+        Ok(params.list(self.client)?.items.first().map(|c| c.id))
+    }
+
+    fn read_checkpoint(&self) -> Result<HashSet<String>, HelpScoutError> {
+        let path = match self.checkpoint {
+            Some(ref path) => path,
+            None => return Ok(HashSet::new()),
+        };
+        match File::open(path) {
+            Ok(file) => Ok(BufReader::new(file).lines().collect::<Result<HashSet<String>, _>>()?),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(HashSet::new()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn write_checkpoint(&self, key: &str) -> Result<(), HelpScoutError> {
+        if let Some(ref path) = self.checkpoint {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            writeln!(file, "{}", key)?;
+            file.sync_data()?;
+        }
+        Ok(())
+    }
+}
+
+/// Group messages into conversations, oldest first.
+///
+/// Messages are in the same conversation when they share a `Message-ID`,
+/// directly or through `References` and `In-Reply-To`.
+pub fn group_conversations(messages: Vec<Message>) -> Vec<Vec<Message>> {
+    let mut parents: Vec<usize> = (0..messages.len()).collect();
+    let mut first_seen: HashMap<String, usize> = HashMap::new();
+    for (i, message) in messages.iter().enumerate() {
+        for id in message.message_id().into_iter().chain(message.references()) {
+            match first_seen.get(&id) {
+                Some(&j) => {
+                    let (a, b) = (root(&mut parents, i), root(&mut parents, j));
+                    parents[a.max(b)] = a.min(b);
+                },
+                None => {
+                    first_seen.insert(id, i);
+                },
+            }
+        }
+    }
+
+    let mut groups: Vec<Vec<(usize, Message)>> = Vec::new();
+    let mut group_of: HashMap<usize, usize> = HashMap::new();
+    for (i, message) in messages.into_iter().enumerate() {
+        let root = root(&mut parents, i);
+        let group = *group_of.entry(root).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push((i, message));
+    }
+
+    for group in &mut groups {
+        group.sort_by_key(|&(i, ref m)| (m.date(), i));
+    }
+    groups.sort_by_key(|group| (group[0].1.date(), group[0].0));
+    groups.into_iter()
+        .map(|group| group.into_iter().map(|(_, m)| m).collect())
+        .collect()
+}
+
+fn root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// The key a conversation is checkpointed under: its first `Message-ID`, or
+/// the first sender, date and subject without one.
+pub fn conversation_key(messages: &[Message]) -> String {
+    let first = &messages[0];
+    first.message_id().unwrap_or_else(|| {
+        format!(
+            "{}|{}|{}",
+            first.header("From").unwrap_or(""),
+            first.header("Date").unwrap_or(""),
+            first.header("Subject").unwrap_or(""),
+        )
+    })
+}
+
+/// The customers in a conversation, as `(display name, email)`: the one it's
+/// with, then anyone else who isn't staff that sent one of the messages.
+pub fn customer_addresses(messages: &[Message], directory: &Directory) -> Vec<(String, String)> {
+    let mut addresses: Vec<(String, String)> = Vec::new();
+    let candidates = conversation_customer(messages, directory).into_iter()
+        .chain(messages.iter().flat_map(|m| m.addresses("From")));
+    for (name, email) in candidates {
+        if !directory.is_staff(&email) && !addresses.iter().any(|&(_, ref e)| *e == email) {
+            addresses.push((name, email));
+        }
+    }
+    addresses
+}
+
+// Whoever wrote in, or who staff first wrote to
+fn conversation_customer(messages: &[Message], directory: &Directory) -> Option<(String, String)> {
+    let first = messages.first()?;
+    let senders = messages.iter().flat_map(|m| m.addresses("From"));
+    let recipients = first.addresses("To").into_iter().chain(first.addresses("Cc"));
+    first.addresses("From").into_iter()
+        .chain(recipients)
+        .chain(senders)
+        .find(|&(_, ref email)| !directory.is_staff(email))
+}
+
+/// A conversation to create from a group of messages, with a thread per
+/// message in the same order. Attachments are left for the caller, as they
+/// need uploading first.
+///
+/// Every customer has to be in `directory` already, and replies sent as
+/// the mailbox address need its `fallback_user`.
+pub fn conversation_from_messages(messages: &[Message], mailbox: MailboxRef, directory: &Directory) -> Result<NewConversation, SkipReason> {
+    let (customer_name, customer_email) = conversation_customer(messages, directory).ok_or(SkipReason::NoCustomer)?;
+    let customer = directory.customer(&customer_name, &customer_email)?;
+
+    let threads = messages.iter().map(|message| {
+        let (name, email) = message.addresses("From").into_iter().next().unwrap_or_default();
+        let (thread_type, created_by) = if directory.is_staff(&email) {
+            (ConversationThreadType::Message, directory.user(&email)?)
+        } else if email.is_empty() {
+            (ConversationThreadType::Customer, directory.customer(&customer_name, &customer_email)?)
+        } else {
+            (ConversationThreadType::Customer, directory.customer(&name, &email)?)
+        };
+
+        let mut thread = NewConversationThread::new(thread_type, created_by, thread_body(message));
+        thread.created_at = message.date();
+        thread.cc = emails(message.addresses("Cc"));
+        thread.bcc = emails(message.addresses("Bcc"));
+        Ok(thread)
+    }).collect::<Result<Vec<NewConversationThread>, SkipReason>>()?;
+
+    let subject = messages[0].header("Subject").map(|s| s.trim()).unwrap_or("");
+    let subject = if subject.is_empty() { "(no subject)" } else { subject };
+
+    let mut conversation = NewConversation::new(customer, subject.into(), mailbox, threads);
+    conversation.conversation_type = Some(ConversationType::Email);
+    conversation.created_at = messages[0].date();
+    Ok(conversation)
+}
+
+// The reasons to skip that don't depend on which customers exist
+fn check_senders(messages: &[Message], directory: &Directory) -> Result<(), SkipReason> {
+    conversation_customer(messages, directory).ok_or(SkipReason::NoCustomer)?;
+    for message in messages {
+        if let Some((_, email)) = message.addresses("From").into_iter().next() {
+            if directory.is_staff(&email) {
+                directory.user(&email)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn emails(addresses: Vec<(String, String)>) -> Option<Vec<String>> {
+    if addresses.is_empty() {
+        None
+    } else {
+        Some(addresses.into_iter().map(|(_, email)| email).collect())
+    }
+}
+
+// Thread bodies are HTML, so plain text is escaped and keeps its line breaks
+fn thread_body(message: &Message) -> String {
+    let body = if message.html {
+        message.body.trim().to_string()
+    } else {
+        message.body.trim()
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace("\r\n", "\n")
+            .replace('\n', "<br>\n")
+    };
+    if body.is_empty() { "(no content)".into() } else { body }
+}
+
+// HelpScout keeps names to 40 characters
+fn split_name(name: &str) -> (Option<String>, Option<String>) {
+    let mut words = name.trim().splitn(2, char::is_whitespace);
+    let part = |s: Option<&str>| s.map(|s| s.trim().chars().take(40).collect::<String>()).filter(|s| !s.is_empty());
+    (part(words.next()), part(words.next()))
+}
@@ -0,0 +1,272 @@
+//! Email Archives
+//!
+//! Conversations as RFC 5322 email, for when they need to leave HelpScout:
+//! legal holds, backups, or another helpdesk. [export](export/index.html)
+//! writes them out as `.eml` files or a single mbox.
+//!
+//! [import](import/index.html) goes the other way, bringing old email into a
+//! mailbox.
+//!
+//! Each thread becomes one [Message](struct.Message.html). Message ids are
+//! made from the conversation and thread ids, so replies thread together in
+//! any mail client and exporting twice gives the same ids.
+use std::io::{self, Write};
+
+use chrono::{DateTime, Utc};
+
+use api::person::Person;
+use id::{ConversationId, ThreadId};
+
+pub mod export;
+pub mod import;
+mod parse;
+
+pub use self::parse::split_mbox;
+
+/// An email, as headers, a body and attachments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Message {
+    /// In the order they're written. Values are unencoded.
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    /// Whether `body` is HTML rather than plain text
+    pub html: bool,
+    pub attachments: Vec<MessageAttachment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageAttachment {
+    pub file_name: String,
+    pub mime_type: String,
+    /// Base64, the way HelpScout stores attachment data
+    pub data: String,
+}
+
+impl Message {
+    /// The first header called `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|&&(ref n, _)| n.eq_ignore_ascii_case(name))
+            .map(|&(_, ref v)| v.as_str())
+    }
+
+    /// Add a header, skipping empty values
+    pub fn push_header<N: Into<String>, V: Into<String>>(&mut self, name: N, value: V) {
+        let value = value.into();
+        if !value.is_empty() {
+            self.headers.push((name.into(), value));
+        }
+    }
+
+    /// The message as an `.eml` file, with CRLF line endings
+    pub fn to_rfc5322(&self) -> String {
+        self.render("\r\n")
+    }
+
+    /// Append the message to an mbox (mboxrd), escaping `From ` lines.
+    pub fn write_mbox<W: Write>(&self, mut out: W) -> io::Result<()> {
+        let from = self.header("From").map(bare_address).unwrap_or("MAILER-DAEMON");
+        let date = self.header("Date")
+            .and_then(|d| DateTime::parse_from_rfc2822(d).ok())
+            .map(|d| d.with_timezone(&Utc))
+            .unwrap_or_else(Utc::now);
+        writeln!(out, "From {} {}", from, date.format("%a %b %e %H:%M:%S %Y"))?;
+
+        for line in self.render("\n").lines() {
+            if line.trim_start_matches('>').starts_with("From ") {
+                out.write_all(b">")?;
+            }
+            out.write_all(line.as_bytes())?;
+            out.write_all(b"\n")?;
+        }
+        out.write_all(b"\n")
+    }
+
+    fn render(&self, newline: &str) -> String {
+        let mut out = String::new();
+        for &(ref name, ref value) in &self.headers {
+            out.push_str(&fold(name, &encode_header(name, value), newline));
+            out.push_str(newline);
+        }
+        out.push_str("MIME-Version: 1.0");
+        out.push_str(newline);
+
+        let content_type = if self.html { "text/html" } else { "text/plain" };
+        if self.attachments.is_empty() {
+            push_part_headers(&mut out, content_type, None, newline);
+            push_base64(&mut out, &encode_base64(self.body.as_bytes()), newline);
+            return out;
+        }
+
+        let boundary = self.boundary();
+        out.push_str(&format!("Content-Type: multipart/mixed; boundary=\"{}\"{}{}", boundary, newline, newline));
+
+        out.push_str(&format!("--{}{}", boundary, newline));
+        push_part_headers(&mut out, content_type, None, newline);
+        push_base64(&mut out, &encode_base64(self.body.as_bytes()), newline);
+
+        for attachment in &self.attachments {
+            out.push_str(&format!("--{}{}", boundary, newline));
+            push_part_headers(&mut out, &attachment.mime_type, Some(&attachment.file_name), newline);
+            push_base64(&mut out, &attachment.data, newline);
+        }
+        out.push_str(&format!("--{}--{}", boundary, newline));
+        out
+    }
+
+    // Stable for a given message, and vanishingly unlikely to appear in it
+    fn boundary(&self) -> String {
+        let id = self.header("Message-ID").unwrap_or("");
+        let seed: u64 = id.bytes().chain(self.body.bytes())
+            .fold(14695981039346656037, |hash, b| (hash ^ b as u64).wrapping_mul(1099511628211));
+        format!("=_helpscout_{:016x}", seed)
+    }
+}
+
+fn push_part_headers(out: &mut String, content_type: &str, file_name: Option<&str>, newline: &str) {
+    match file_name {
+        Some(name) => {
+            let name = encode_word(name).replace('"', "'");
+            out.push_str(&format!("Content-Type: {}; name=\"{}\"{}", content_type, name, newline));
+            out.push_str(&format!("Content-Disposition: attachment; filename=\"{}\"{}", name, newline));
+        },
+        None => out.push_str(&format!("Content-Type: {}; charset=utf-8{}", content_type, newline)),
+    }
+    out.push_str("Content-Transfer-Encoding: base64");
+    out.push_str(newline);
+    out.push_str(newline);
+}
+
+// Base64 wrapped at 76 columns, ignoring any wrapping it came with
+fn push_base64(out: &mut String, data: &str, newline: &str) {
+    let data: Vec<u8> = data.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    for line in data.chunks(76) {
+        out.push_str(&String::from_utf8_lossy(line));
+        out.push_str(newline);
+    }
+}
+
+/// `<conversation-1234@domain>`, the id a conversation's messages reference
+pub fn conversation_message_id(conversation: ConversationId, domain: &str) -> String {
+    format!("<conversation-{}@{}>", conversation, domain)
+}
+
+/// `<thread-5678.conversation-1234@domain>`
+pub fn thread_message_id(conversation: ConversationId, thread: ThreadId, domain: &str) -> String {
+    format!("<thread-{}.conversation-{}@{}>", thread, conversation, domain)
+}
+
+/// `"First Last" <email>`, or None without an email
+pub fn person_address(person: &Person) -> Option<String> {
+    let email = person.email.as_ref()?;
+    let name: Vec<&str> = person.first_name.iter().chain(person.last_name.iter())
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if name.is_empty() {
+        Some(email.clone())
+    } else {
+        Some(format!("\"{}\" <{}>", name.join(" ").replace('"', "'"), email))
+    }
+}
+
+// The email out of `"Name" <email>`
+fn bare_address(address: &str) -> &str {
+    match (address.rfind('<'), address.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &address[start + 1..end],
+        _ => address.trim(),
+    }
+}
+
+// Non-ASCII text headers become RFC 2047 encoded words. Address headers
+// only have their display names encoded.
+fn encode_header(name: &str, value: &str) -> String {
+    if value.is_ascii() {
+        return value.to_string();
+    }
+    match name.to_lowercase().as_str() {
+        "from" | "to" | "cc" | "bcc" | "reply-to" | "sender" => {
+            value.split(", ").map(|address| {
+                match address.rfind(" <") {
+                    Some(i) => format!("{}{}", encode_word(address[..i].trim_matches('"')), &address[i..]),
+                    None => address.to_string(),
+                }
+            }).collect::<Vec<String>>().join(", ")
+        },
+        _ => encode_word(value),
+    }
+}
+
+// Encoded words are limited to 75 characters, so long text is split into
+// several, never inside a character.
+fn encode_word(text: &str) -> String {
+    if text.is_ascii() {
+        return text.to_string();
+    }
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if i + c.len_utf8() - start > 39 {
+            words.push(&text[start..i]);
+            start = i;
+        }
+    }
+    words.push(&text[start..]);
+    words.iter()
+        .map(|w| format!("=?utf-8?B?{}?=", encode_base64(w.as_bytes())))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+// Wrap long headers at spaces so lines stay under 78 columns where possible
+fn fold(name: &str, value: &str, newline: &str) -> String {
+    let mut out = format!("{}:", name);
+    let mut line_len = out.len();
+    for word in value.split(' ') {
+        if line_len + 1 + word.len() > 78 && line_len > name.len() + 1 {
+            out.push_str(newline);
+            line_len = 0;
+        }
+        out.push(' ');
+        out.push_str(word);
+        line_len += 1 + word.len();
+    }
+    out
+}
+
+const BASE64: &'static [u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub(crate) fn encode_base64(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+pub(crate) fn decode_base64(data: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(data.len() / 4 * 3);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for b in data.bytes().filter(|b| !b.is_ascii_whitespace()) {
+        if b == b'=' {
+            break;
+        }
+        let value = BASE64.iter().position(|&c| c == b)?;
+        n = n << 6 | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((n >> bits) as u8);
+        }
+    }
+    Some(out)
+}
@@ -0,0 +1,427 @@
+use chrono::{DateTime, Utc};
+
+use error::HelpScoutError;
+use super::{Message, MessageAttachment, decode_base64, encode_base64};
+
+// Deeper than any real message nests its parts
+const MAX_DEPTH: usize = 10;
+
+const MIME_HEADERS: &'static [&'static str] = &["MIME-Version", "Content-Type", "Content-Transfer-Encoding", "Content-Disposition"];
+
+/// Reading messages back in
+impl Message {
+    /// Parse an `.eml` file.
+    ///
+    /// Header values are decoded, and the MIME ones dropped. The body is the
+    /// HTML part if there is one and the plain text part if not. Any other
+    /// part becomes an attachment.
+    pub fn parse(raw: &[u8]) -> Result<Message, HelpScoutError> {
+        let (headers, body) = split_entity(raw);
+        if headers.is_empty() {
+            return Err(HelpScoutError::InputParseError("Message has no headers".into()));
+        }
+
+        let mut parts = Parts::default();
+        walk(&headers, body, &mut parts, 0);
+
+        // The MIME headers only describe the encoding, which is undone here
+        let mut message = Message::default();
+        message.headers = headers.into_iter()
+            .filter(|&(ref name, _)| !MIME_HEADERS.iter().any(|h| name.eq_ignore_ascii_case(h)))
+            .collect();
+        message.attachments = parts.attachments;
+        match (parts.html, parts.plain) {
+            (Some(html), _) => {
+                message.body = html;
+                message.html = true;
+            },
+            (None, Some(plain)) => message.body = plain,
+            (None, None) => {},
+        }
+        Ok(message)
+    }
+
+    /// The `(display name, email)` pairs in an address header
+    pub fn addresses(&self, name: &str) -> Vec<(String, String)> {
+        self.headers.iter()
+            .filter(|&&(ref n, _)| n.eq_ignore_ascii_case(name))
+            .flat_map(|&(_, ref v)| parse_addresses(v))
+            .collect()
+    }
+
+    /// The `Date` header
+    pub fn date(&self) -> Option<DateTime<Utc>> {
+        // Drop a trailing comment like `(UTC)`, which chrono doesn't accept
+        let date = self.header("Date")?;
+        let date = date.split('(').next().unwrap_or(date).trim();
+        DateTime::parse_from_rfc2822(date).ok().map(|d| d.with_timezone(&Utc))
+    }
+
+    /// The `Message-ID` header, with its angle brackets
+    pub fn message_id(&self) -> Option<String> {
+        self.header("Message-ID").and_then(|id| message_ids(id).into_iter().next())
+    }
+
+    /// Every message this one refers to, from `References` and `In-Reply-To`
+    pub fn references(&self) -> Vec<String> {
+        let mut ids = Vec::new();
+        for name in &["References", "In-Reply-To"] {
+            for id in self.header(name).map(message_ids).unwrap_or_default() {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        ids
+    }
+}
+
+/// Split an mbox file into its messages, undoing mboxrd `>From ` escaping.
+pub fn split_mbox(raw: &[u8]) -> Vec<Vec<u8>> {
+    let mut messages = Vec::new();
+    let mut current: Option<Vec<u8>> = None;
+    for line in lines(raw) {
+        if line.starts_with(b"From ") {
+            messages.extend(current.take());
+            current = Some(Vec::new());
+            continue;
+        }
+        // Anything before the first `From ` line isn't a message
+        if let Some(ref mut message) = current {
+            let quotes = line.iter().take_while(|&&b| b == b'>').count();
+            if quotes > 0 && line[quotes..].starts_with(b"From ") {
+                message.extend_from_slice(&line[1..]);
+            } else {
+                message.extend_from_slice(line);
+            }
+        }
+    }
+    messages.extend(current);
+    messages
+}
+
+#[derive(Default)]
+struct Parts {
+    html: Option<String>,
+    plain: Option<String>,
+    attachments: Vec<MessageAttachment>,
+}
+
+fn walk(headers: &[(String, String)], body: &[u8], parts: &mut Parts, depth: usize) {
+    let (mime_type, params) = header(headers, "Content-Type")
+        .map(parse_content_type)
+        .unwrap_or_else(|| ("text/plain".to_string(), Vec::new()));
+
+    if mime_type.starts_with("multipart/") && depth < MAX_DEPTH {
+        if let Some(boundary) = param(&params, "boundary") {
+            for part in split_multipart(body, &boundary) {
+                let (part_headers, part_body) = split_entity(part);
+                walk(&part_headers, part_body, parts, depth + 1);
+            }
+            return;
+        }
+    }
+
+    let data = match header(headers, "Content-Transfer-Encoding").map(|e| e.to_lowercase()) {
+        Some(ref e) if e == "base64" => decode_base64(&String::from_utf8_lossy(body)).unwrap_or_else(|| body.to_vec()),
+        Some(ref e) if e == "quoted-printable" => decode_quoted_printable(body),
+        _ => body.to_vec(),
+    };
+
+    let (disposition, disposition_params) = header(headers, "Content-Disposition")
+        .map(parse_content_type)
+        .unwrap_or_default();
+    let file_name = param(&disposition_params, "filename").or_else(|| param(&params, "name"));
+    let charset = param(&params, "charset").unwrap_or_default();
+
+    if disposition != "attachment" && file_name.is_none() {
+        if mime_type == "text/html" && parts.html.is_none() {
+            parts.html = Some(decode_charset(&data, &charset));
+            return;
+        }
+        if mime_type == "text/plain" && parts.plain.is_none() {
+            parts.plain = Some(decode_charset(&data, &charset));
+            return;
+        }
+    }
+
+    parts.attachments.push(MessageAttachment {
+        file_name: file_name.unwrap_or_else(|| "attachment".into()),
+        mime_type: mime_type,
+        data: encode_base64(&data),
+    });
+}
+
+fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers.iter()
+        .find(|&&(ref n, _)| n.eq_ignore_ascii_case(name))
+        .map(|&(_, ref v)| v.as_str())
+}
+
+fn param(params: &[(String, String)], name: &str) -> Option<String> {
+    params.iter()
+        .find(|&&(ref n, _)| n.eq_ignore_ascii_case(name))
+        .map(|&(_, ref v)| v.clone())
+}
+
+// Lines with their endings
+fn lines(raw: &[u8]) -> Vec<&[u8]> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    while start < raw.len() {
+        let end = raw[start..].iter().position(|&b| b == b'\n').map(|i| start + i + 1).unwrap_or(raw.len());
+        lines.push(&raw[start..end]);
+        start = end;
+    }
+    lines
+}
+
+fn trim_newline(line: &[u8]) -> &[u8] {
+    let line = if line.ends_with(b"\n") { &line[..line.len() - 1] } else { line };
+    if line.ends_with(b"\r") { &line[..line.len() - 1] } else { line }
+}
+
+// Headers, unfolded and decoded, and the body after them
+fn split_entity(raw: &[u8]) -> (Vec<(String, String)>, &[u8]) {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let mut offset = 0;
+    for line in lines(raw) {
+        offset += line.len();
+        let line = String::from_utf8_lossy(trim_newline(line)).into_owned();
+        if line.trim().is_empty() {
+            break;
+        }
+        if line.starts_with(' ') || line.starts_with('\t') {
+            if let Some(&mut (_, ref mut value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some(colon) = line.find(':') {
+            headers.push((line[..colon].trim().to_string(), line[colon + 1..].trim().to_string()));
+        }
+    }
+    let headers = headers.into_iter().map(|(name, value)| (name, decode_words(&value))).collect();
+    (headers, &raw[offset.min(raw.len())..])
+}
+
+// The parts between `--boundary` lines, up to `--boundary--`
+fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{}", boundary);
+    let mut parts = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+    for line in lines(body) {
+        let end = offset + line.len();
+        let trimmed = trim_newline(line);
+        if trimmed.starts_with(delimiter.as_bytes()) {
+            let rest = String::from_utf8_lossy(&trimmed[delimiter.len()..]);
+            let rest = rest.trim_end();
+            if rest.is_empty() || rest == "--" {
+                if let Some(start) = start {
+                    // The newline before a delimiter belongs to it
+                    parts.push(trim_newline(&body[start..offset]));
+                }
+                if rest == "--" {
+                    return parts;
+                }
+                start = Some(end);
+            }
+        }
+        offset = end;
+    }
+    if let Some(start) = start {
+        parts.push(&body[start..]);
+    }
+    parts
+}
+
+// `type/subtype; name="value"; ...`, lowercasing the type and names.
+// RFC 2231 `name*=charset''percent%20encoded` values are decoded too.
+fn parse_content_type(value: &str) -> (String, Vec<(String, String)>) {
+    let mut pieces = split_outside_quotes(value, ';').into_iter();
+    let mime_type = pieces.next().unwrap_or_default().trim().to_lowercase();
+    let params = pieces.filter_map(|piece| {
+        let equals = piece.find('=')?;
+        let name = piece[..equals].trim().to_lowercase();
+        let value = piece[equals + 1..].trim().trim_matches('"').to_string();
+        if name.ends_with('*') {
+            let mut encoded = value.splitn(3, '\'');
+            let charset = encoded.next().unwrap_or_default();
+            let text = encoded.nth(1).unwrap_or(charset);
+            Some((name.trim_end_matches('*').to_string(), decode_charset(&decode_percent(text), charset)))
+        } else {
+            Some((name, value))
+        }
+    }).collect();
+    (mime_type, params)
+}
+
+fn split_outside_quotes(value: &str, separator: char) -> Vec<String> {
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut angled = false;
+    for c in value.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '<' if !quoted => angled = true,
+            '>' if !quoted => angled = false,
+            c if c == separator && !quoted && !angled => {
+                pieces.push(current.clone());
+                current.clear();
+                continue;
+            },
+            _ => {},
+        }
+        current.push(c);
+    }
+    pieces.push(current);
+    pieces
+}
+
+fn parse_addresses(value: &str) -> Vec<(String, String)> {
+    split_outside_quotes(value, ',').iter().filter_map(|address| {
+        let address = address.trim();
+        let (name, email) = match (address.rfind('<'), address.rfind('>')) {
+            (Some(start), Some(end)) if start < end => (address[..start].trim(), address[start + 1..end].trim()),
+            _ => ("", address),
+        };
+        if email.contains('@') {
+            Some((name.trim_matches('"').trim().to_string(), email.to_lowercase()))
+        } else {
+            None
+        }
+    }).collect()
+}
+
+fn message_ids(value: &str) -> Vec<String> {
+    value.split('<')
+        .skip(1)
+        .filter_map(|id| id.find('>').map(|end| format!("<{}>", id[..end].trim())))
+        .collect()
+}
+
+// RFC 2047 encoded words, dropping the whitespace between adjacent ones
+fn decode_words(value: &str) -> String {
+    let mut out = String::new();
+    let mut rest = value;
+    let mut after_word = false;
+    while let Some(start) = rest.find("=?") {
+        let (before, candidate) = rest.split_at(start);
+        match decode_word(candidate) {
+            Some((text, len)) => {
+                if !(after_word && before.trim().is_empty()) {
+                    out.push_str(before);
+                }
+                out.push_str(&text);
+                rest = &candidate[len..];
+                after_word = true;
+            },
+            None => {
+                out.push_str(before);
+                out.push_str("=?");
+                rest = &candidate[2..];
+                after_word = false;
+            },
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// `=?charset?B|Q?text?=` at the start of `word`, and how long it was
+fn decode_word(word: &str) -> Option<(String, usize)> {
+    let mut pieces = word[2..].splitn(3, '?');
+    let charset = pieces.next()?;
+    let encoding = pieces.next()?;
+    let rest = pieces.next()?;
+    let end = rest.find("?=")?;
+    let text = &rest[..end];
+    if text.contains(' ') {
+        return None;
+    }
+    let bytes = match encoding {
+        "B" | "b" => decode_base64(text)?,
+        "Q" | "q" => decode_quoted_printable(text.replace('_', " ").as_bytes()),
+        _ => return None,
+    };
+    let len = "=?".len() + charset.len() + "?".len() + encoding.len() + "?".len() + end + "?=".len();
+    // A language can follow the charset, as in `utf-8*en`
+    let charset = charset.split('*').next().unwrap_or(charset);
+    Some((decode_charset(&bytes, charset), len))
+}
+
+fn decode_quoted_printable(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut i = 0;
+    while i < data.len() {
+        if data[i] != b'=' {
+            out.push(data[i]);
+            i += 1;
+            continue;
+        }
+        let rest = &data[i + 1..];
+        if rest.starts_with(b"\r\n") {
+            i += 3;
+        } else if rest.starts_with(b"\n") {
+            i += 2;
+        } else {
+            match rest.get(0..2).and_then(|hex| u8::from_str_radix(&String::from_utf8_lossy(hex), 16).ok()) {
+                Some(byte) => {
+                    out.push(byte);
+                    i += 3;
+                },
+                None => {
+                    out.push(b'=');
+                    i += 1;
+                },
+            }
+        }
+    }
+    out
+}
+
+fn decode_percent(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes.get(i + 1..i + 3).and_then(|hex| u8::from_str_radix(&String::from_utf8_lossy(hex), 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                out.push(byte);
+                i += 3;
+            },
+            (byte, _) => {
+                out.push(byte);
+                i += 1;
+            },
+        }
+    }
+    out
+}
+
+// UTF-8 and its subsets, or Latin-1 for the single byte charsets old mail
+// tends to use. Anything else is read as UTF-8, replacing what doesn't fit.
+fn decode_charset(data: &[u8], charset: &str) -> String {
+    match charset.to_lowercase().as_str() {
+        "iso-8859-1" | "iso-8859-15" | "latin1" => data.iter().map(|&b| b as char).collect(),
+        "windows-1252" | "cp1252" => data.iter().map(|&b| windows_1252(b)).collect(),
+        _ => String::from_utf8_lossy(data).into_owned(),
+    }
+}
+
+// Windows-1252 is Latin-1 with printable characters in place of most of the
+// 0x80-0x9F control codes. The five it leaves unassigned keep their code.
+fn windows_1252(b: u8) -> char {
+    const HIGH: [char; 32] = [
+        '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+        '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+        '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+        '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+    ];
+    match b {
+        0x80..=0x9F => HIGH[(b - 0x80) as usize],
+        _ => b as char,
+    }
+}
@@ -0,0 +1,364 @@
+//! Service Level Agreements
+//!
+//! HelpScout doesn't track SLAs, so this works them out from conversation
+//! and thread timestamps. Define [policies](struct.Policy.html) with first
+//! response and resolution targets, and the [tracker](struct.SlaTracker.html)
+//! tells you which conversations have breached, which are about to, and how
+//! long is left.
+//!
+//! Targets count [business hours](../calendar/struct.BusinessCalendar.html), so a
+//! conversation that comes in on Friday night isn't late by Monday morning.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! extern crate helpscout;
+//! extern crate chrono;
+//!
+//! use chrono::prelude::*;
+//! use chrono::Duration;
+//!
+//! use helpscout::MailboxId;
+//! use helpscout::api::conversations::{self, ConversationListParamBuilder};
+//! use helpscout::calendar::BusinessCalendar;
+//! use helpscout::sla::{Policy, Priority, SlaTracker};
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let calendar = BusinessCalendar::new(FixedOffset::west_opt(5 * 3600).unwrap())
+//!         .weekdays(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap())
+//!         .holiday(NaiveDate::from_ymd_opt(2018, 12, 25).unwrap());
+//!
+//!     let tracker = SlaTracker::new()
+//!         .priority_tag("urgent", Priority::Urgent)
+//!         .policy(Policy::new("Urgent")
+//!             .priority(Priority::Urgent)
+//!             .first_response(Duration::hours(1))
+//!             .resolution(Duration::hours(8)))
+//!         .policy(Policy::new("Support")
+//!             .mailbox(MailboxId(1234))
+//!             .first_response(Duration::hours(4))
+//!             .resolution(Duration::days(2))
+//!             .warn_before(Duration::hours(1))
+//!             .business_hours(calendar));
+//!
+//!     let conversations = conversations::list(&client, MailboxId(1234), &mut ConversationListParamBuilder::new())
+//!         .expect("list conversations");
+//!     for status in tracker.evaluate_all(&conversations.items, Utc::now()) {
+//!         if status.breached() || status.at_risk() {
+//!             println!("{:?}", status);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Conversations from a list don't include their threads, so their first
+//! response comes back [Unknown](enum.SlaState.html#variant.Unknown). Fetch
+//! the conversation on its own, or use the webhook payload, to get it.
+//!
+//! Webhook bodies are conversations too:
+//!
+//! ```rust,ignore
+//! let status = tracker.evaluate_webhook(&body, Utc::now())?;
+//! ```
+
+use chrono::{DateTime, Duration, Utc};
+use serde_json;
+
+use api::conversations::{Conversation, ConversationStatus, ConversationThread, ConversationThreadState, ConversationThreadType};
+use calendar::BusinessCalendar;
+use error::HelpScoutError;
+use id::{ConversationId, MailboxId};
+
+/// HelpScout conversations don't have a priority, so it's read from tags
+/// with [priority_tag](struct.SlaTracker.html#method.priority_tag).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+    Urgent,
+}
+
+impl Default for Priority {
+    fn default() -> Priority {
+        Priority::Normal
+    }
+}
+
+/// Targets for the conversations a policy matches.
+///
+/// Every condition set has to match. A policy with none matches everything,
+/// which makes a good last policy.
+#[derive(Debug, Clone)]
+pub struct Policy {
+    pub name: String,
+    pub mailboxes: Vec<MailboxId>,
+    pub tags: Vec<String>,
+    pub priority: Option<Priority>,
+    pub first_response: Option<Duration>,
+    pub resolution: Option<Duration>,
+    pub warn_before: Duration,
+    pub calendar: BusinessCalendar,
+}
+
+impl Policy {
+    pub fn new<S: Into<String>>(name: S) -> Policy {
+        Policy {
+            name: name.into(),
+            mailboxes: Vec::new(),
+            tags: Vec::new(),
+            priority: None,
+            first_response: None,
+            resolution: None,
+            warn_before: Duration::zero(),
+            calendar: BusinessCalendar::always(),
+        }
+    }
+
+    /// Match conversations in this mailbox. Can be called more than once.
+    pub fn mailbox(mut self, mailbox: MailboxId) -> Self {
+        self.mailboxes.push(mailbox);
+        self
+    }
+
+    /// Match conversations with this tag. Can be called more than once, and
+    /// any one of the tags will do.
+    pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Match conversations of this priority
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = Some(priority);
+        self
+    }
+
+    /// Time allowed until the first reply
+    pub fn first_response(mut self, target: Duration) -> Self {
+        self.first_response = Some(target);
+        self
+    }
+
+    /// Time allowed until the conversation is closed
+    pub fn resolution(mut self, target: Duration) -> Self {
+        self.resolution = Some(target);
+        self
+    }
+
+    /// Mark targets at risk once this little time is left
+    pub fn warn_before(mut self, warn_before: Duration) -> Self {
+        self.warn_before = warn_before;
+        self
+    }
+
+    /// When the clock runs, around the clock unless set
+    pub fn business_hours(mut self, calendar: BusinessCalendar) -> Self {
+        self.calendar = calendar;
+        self
+    }
+
+    fn matches(&self, conversation: &Conversation, priority: Priority) -> bool {
+        (self.mailboxes.is_empty() || self.mailboxes.contains(&conversation.mailbox.id))
+            && (self.tags.is_empty() || conversation.tags.iter().any(|t| self.tags.contains(t)))
+            && self.priority.map_or(true, |p| p == priority)
+    }
+
+    fn target(&self, target: Duration, start: DateTime<Utc>, done: Option<DateTime<Utc>>, known: bool, now: DateTime<Utc>) -> TargetStatus {
+        // A calendar with no working hours never lets the clock run out
+        let due = match self.calendar.add(start, target) {
+            Some(due) => due,
+            None => {
+                return TargetStatus {
+                    state: SlaState::Unknown,
+                    due: None,
+                    completed_at: done,
+                    remaining: None,
+                };
+            },
+        };
+        let until = done.unwrap_or(now);
+        let remaining = if until <= due {
+            self.calendar.between(until, due)
+        } else {
+            -self.calendar.between(due, until)
+        };
+
+        let state = match done {
+            Some(done) if done <= due => SlaState::Met,
+            Some(_) => SlaState::Breached,
+            None if !known => SlaState::Unknown,
+            None if remaining <= Duration::zero() => SlaState::Breached,
+            None if remaining <= self.warn_before => SlaState::AtRisk,
+            None => SlaState::OnTrack,
+        };
+
+        TargetStatus {
+            state: state,
+            due: Some(due),
+            completed_at: done,
+            remaining: Some(remaining),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlaState {
+    /// Done in time
+    Met,
+    /// Still open with time to spare
+    OnTrack,
+    /// Still open and within the policy's warning time
+    AtRisk,
+    /// Done late, or still open past the due time
+    Breached,
+    /// Can't tell from what HelpScout sent, usually a conversation from a
+    /// list without its threads, or a closed one without `closedAt`. Also
+    /// used when the policy's calendar has no working hours at all.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetStatus {
+    pub state: SlaState,
+    /// `None` if the policy's calendar has no working hours to count
+    pub due: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Business time left when it was completed, or now if it hasn't been.
+    /// Negative once overdue.
+    pub remaining: Option<Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlaStatus {
+    pub conversation: ConversationId,
+    pub policy: String,
+    pub priority: Priority,
+    pub first_response: Option<TargetStatus>,
+    pub resolution: Option<TargetStatus>,
+}
+
+impl SlaStatus {
+    fn targets(&self) -> Vec<&TargetStatus> {
+        self.first_response.iter().chain(self.resolution.iter()).collect()
+    }
+
+    pub fn breached(&self) -> bool {
+        self.targets().iter().any(|t| t.state == SlaState::Breached)
+    }
+
+    pub fn at_risk(&self) -> bool {
+        self.targets().iter().any(|t| t.state == SlaState::AtRisk)
+    }
+
+    /// The next due time still open, if any
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        self.targets().iter()
+            .filter(|t| t.completed_at.is_none())
+            .filter_map(|t| t.due)
+            .min()
+    }
+}
+
+/// Matches conversations to policies and checks them against their targets.
+#[derive(Debug, Clone, Default)]
+pub struct SlaTracker {
+    pub policies: Vec<Policy>,
+    pub priority_tags: Vec<(String, Priority)>,
+}
+
+impl SlaTracker {
+    pub fn new() -> SlaTracker {
+        SlaTracker::default()
+    }
+
+    /// Add a policy. The first policy that matches a conversation is used.
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policies.push(policy);
+        self
+    }
+
+    /// Conversations tagged `tag` have this priority. With several priority
+    /// tags the highest wins, and with none it's `Normal`.
+    pub fn priority_tag<S: Into<String>>(mut self, tag: S, priority: Priority) -> Self {
+        self.priority_tags.push((tag.into(), priority));
+        self
+    }
+
+    pub fn priority(&self, conversation: &Conversation) -> Priority {
+        self.priority_tags.iter()
+            .filter(|&&(ref tag, _)| conversation.tags.contains(tag))
+            .map(|&(_, priority)| priority)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn policy_for(&self, conversation: &Conversation) -> Option<&Policy> {
+        let priority = self.priority(conversation);
+        self.policies.iter().find(|p| p.matches(conversation, priority))
+    }
+
+    /// Check a conversation as of `now`. Drafts, spam and conversations no
+    /// policy matches have no SLA.
+    pub fn evaluate(&self, conversation: &Conversation, now: DateTime<Utc>) -> Option<SlaStatus> {
+        if conversation.is_draft || conversation.status == ConversationStatus::Spam {
+            return None;
+        }
+        let policy = self.policy_for(conversation)?;
+        let start = conversation.created_at;
+
+        let first_response = policy.first_response.map(|target| {
+            let (responded, known) = match conversation.threads {
+                Some(ref threads) => (first_response_at(threads), true),
+                None => (None, false),
+            };
+            policy.target(target, start, responded, known, now)
+        });
+
+        let resolution = policy.resolution.map(|target| {
+            // A closed conversation without closedAt was resolved at some
+            // point we can't tell
+            let (resolved, known) = match conversation.status {
+                ConversationStatus::Closed => (conversation.closed_at, conversation.closed_at.is_some()),
+                _ => (None, true),
+            };
+            policy.target(target, start, resolved, known, now)
+        });
+
+        Some(SlaStatus {
+            conversation: conversation.id,
+            policy: policy.name.clone(),
+            priority: self.priority(conversation),
+            first_response: first_response,
+            resolution: resolution,
+        })
+    }
+
+    /// Check a page of conversations, skipping the ones without an SLA
+    pub fn evaluate_all(&self, conversations: &[Conversation], now: DateTime<Utc>) -> Vec<SlaStatus> {
+        conversations.iter()
+            .filter_map(|c| self.evaluate(c, now))
+            .collect()
+    }
+
+    /// Check the conversation in a conversation webhook's body
+    pub fn evaluate_webhook(&self, data: &str, now: DateTime<Utc>) -> Result<Option<SlaStatus>, HelpScoutError> {
+        let conversation: Conversation = serde_json::from_str(data)?;
+        Ok(self.evaluate(&conversation, now))
+    }
+}
+
+/// When a user first replied to the customer. Notes, line items and drafts
+/// don't count.
+pub fn first_response_at(threads: &[ConversationThread]) -> Option<DateTime<Utc>> {
+    threads.iter()
+        .filter(|t| !t.created_by_customer)
+        .filter(|t| match t.conversation_thread_type {
+            ConversationThreadType::Message | ConversationThreadType::Chat | ConversationThreadType::Phone => true,
+            _ => false,
+        })
+        .filter(|t| t.state.as_ref().map_or(true, |s| *s == ConversationThreadState::Published))
+        .map(|t| t.created_at)
+        .min()
+}
@@ -0,0 +1,273 @@
+//! Local Mirror
+//!
+//! Copies the helpdesk into a SQLite database for analytics: mailboxes,
+//! folders, conversations (with threads), customers, users, teams and tags.
+//! Needs the `sync` feature.
+//!
+//! The first [sync](struct.Mirror.html#method.sync) fetches everything.
+//! After that conversations and customers are fetched with `modifiedSince`
+//! from where the last run got to, which is kept per mailbox in the
+//! `sync_cursors` table. The other resources are small and are fetched in
+//! full each time.
+//!
+//! HelpScout doesn't list deleted conversations, so they're removed when the
+//! webhook says so with [apply_webhook](struct.Mirror.html#method.apply_webhook).
+//!
+//! ## Usage
+//!
+//! ```rust,ignore
+//! extern crate helpscout;
+//!
+//! use helpscout::sync::Mirror;
+//!
+//! fn main() {
+//!     let client = helpscout::Client::example();
+//!     let mut mirror = Mirror::open("helpscout.sqlite")
+//!         .expect("open mirror")
+//!         .fetch_threads(true);
+//!
+//!     let summary = mirror.sync(&client).expect("sync");
+//!     println!("{:?}", summary);
+//!
+//!     let open: i64 = mirror.connection()
+//!         .query_row("SELECT count(*) FROM conversations WHERE status = 'active'", &[], |row| row.get(0))
+//!         .unwrap();
+//!     println!("{} active conversations", open);
+//! }
+//! ```
+//!
+//! From a webhook handler, after checking the signature:
+//!
+//! ```rust,ignore
+//! mirror.apply_webhook(&event_header, &body)?;
+//! ```
+use std::path::Path;
+
+use chrono::Utc;
+use rusqlite::Connection;
+use serde_json;
+
+use api;
+use api::conversations::{self, Conversation, ConversationListParamBuilder};
+use api::customers::Customer;
+use api::mailboxes;
+use api::tags;
+use client::Client;
+use envelope::all_pages;
+use error::HelpScoutError;
+use id::{ConversationId, MailboxId, TeamId};
+
+mod schema;
+mod store;
+
+/// What a sync run wrote.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SyncSummary {
+    pub mailboxes: usize,
+    pub folders: usize,
+    pub conversations: usize,
+    pub customers: usize,
+    pub users: usize,
+    pub teams: usize,
+    pub tags: usize,
+}
+
+/// A SQLite copy of the helpdesk.
+#[derive(Debug)]
+pub struct Mirror {
+    conn: Connection,
+    fetch_threads: bool,
+}
+
+impl Mirror {
+    /// Open or create the mirror at `path`
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Mirror, HelpScoutError> {
+        Mirror::new(Connection::open(path)?)
+    }
+
+    /// A mirror that only lives as long as this value
+    pub fn open_in_memory() -> Result<Mirror, HelpScoutError> {
+        Mirror::new(Connection::open_in_memory()?)
+    }
+
+    fn new(conn: Connection) -> Result<Mirror, HelpScoutError> {
+        conn.execute_batch(schema::SCHEMA)?;
+        Ok(Mirror {
+            conn: conn,
+            fetch_threads: false,
+        })
+    }
+
+    /// Also fetch each changed conversation on its own to get its threads.
+    /// That's a request per conversation, so it's off unless set.
+    pub fn fetch_threads(mut self, fetch_threads: bool) -> Self {
+        self.fetch_threads = fetch_threads;
+        self
+    }
+
+    /// The database, for querying
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// Bring the mirror up to date.
+    ///
+    /// Each page is written in its own transaction and cursors only move once
+    /// a resource is done, so a failed run can simply be run again.
+    pub fn sync(&mut self, client: &Client) -> Result<SyncSummary, HelpScoutError> {
+        let mut summary = SyncSummary::default();
+
+        let mailboxes = all_pages(|page| mailboxes::list(client, Some(page)))?;
+        for mailbox in &mailboxes {
+            let folders = all_pages(|page| mailboxes::get_folders(client, mailbox.id, Some(page)))?;
+            self.transaction(|mirror| {
+                mirror.save_mailbox(mailbox)?;
+                for folder in &folders {
+                    mirror.save_folder(mailbox.id, folder)?;
+                }
+                Ok(())
+            })?;
+            summary.mailboxes += 1;
+            summary.folders += folders.len();
+        }
+
+        for mailbox in &mailboxes {
+            summary.conversations += self.sync_conversations(client, mailbox.id)?;
+        }
+        summary.customers = self.sync_customers(client)?;
+
+        let users = all_pages(|page| api::users().page(page).list(client))?;
+        self.transaction(|mirror| users.iter().map(|u| mirror.save_user(u)).collect::<Result<(), _>>())?;
+        summary.users = users.len();
+
+        let teams = all_pages(|page| api::teams().page(page).list(client))?;
+        for team in &teams {
+            // Teams are listed as users of type `team`; the user id is the team id
+            let id = TeamId(team.id.0);
+            let members: Vec<_> = all_pages(|page| api::teams().page(page).list_team_members(client, id))?
+                .iter()
+                .map(|u| u.id)
+                .collect();
+            self.transaction(|mirror| mirror.save_team(team, &members))?;
+        }
+        summary.teams = teams.len();
+
+        let tags = all_pages(|page| tags::list(client, Some(page)))?;
+        self.transaction(|mirror| tags.iter().map(|t| mirror.save_tag(t)).collect::<Result<(), _>>())?;
+        summary.tags = tags.len();
+
+        Ok(summary)
+    }
+
+    fn sync_conversations(&mut self, client: &Client, mailbox: MailboxId) -> Result<usize, HelpScoutError> {
+        let resource = format!("conversations/{}", mailbox);
+        let started = Utc::now();
+        let since = self.cursor(&resource)?;
+        let mut count = 0;
+        let mut page = 1;
+
+        loop {
+            let mut params = ConversationListParamBuilder::new();
+            params.page(page);
+            if let Some(since) = since {
+                params.modified_since(since);
+            }
+            let collection = conversations::list(client, mailbox, &mut params)?;
+
+            let conversations = if self.fetch_threads {
+                collection.items.iter()
+                    .map(|c| conversations::get(client, c.id).map(|item| item.item))
+                    .collect::<Result<Vec<Conversation>, HelpScoutError>>()?
+            } else {
+                collection.items
+            };
+            self.transaction(|mirror| conversations.iter().map(|c| mirror.save_conversation(c)).collect::<Result<(), _>>())?;
+            count += conversations.len();
+
+            if page >= collection.pages {
+                break;
+            }
+            page += 1;
+        }
+
+        self.set_cursor(&resource, started)?;
+        Ok(count)
+    }
+
+    fn sync_customers(&mut self, client: &Client) -> Result<usize, HelpScoutError> {
+        let started = Utc::now();
+        let since = self.cursor("customers")?;
+        let mut count = 0;
+        let mut page = 1;
+
+        loop {
+            let mut params = api::customers();
+            params.page(page);
+            if let Some(since) = since {
+                params.modified_since(since);
+            }
+            let collection = params.list(client)?;
+            self.transaction(|mirror| collection.items.iter().map(|c| mirror.save_customer(c)).collect::<Result<(), _>>())?;
+            count += collection.items.len();
+
+            if page >= collection.pages {
+                break;
+            }
+            page += 1;
+        }
+
+        self.set_cursor("customers", started)?;
+        Ok(count)
+    }
+
+    /// Apply a webhook. `event` is the `X-HelpScout-Event` header and `data`
+    /// the body. Returns whether the event was one the mirror keeps track of.
+    pub fn apply_webhook(&mut self, event: &str, data: &str) -> Result<bool, HelpScoutError> {
+        match event {
+            "convo.deleted" => {
+                let deleted: DeletedConversation = serde_json::from_str(data)?;
+                self.transaction(|mirror| mirror.delete_conversation(deleted.id))?;
+                Ok(true)
+            },
+            e if e.starts_with("convo.") => {
+                let conversation: Conversation = serde_json::from_str(data)?;
+                self.transaction(|mirror| mirror.save_conversation(&conversation))?;
+                Ok(true)
+            },
+            e if e.starts_with("customer.") => {
+                let customer: Customer = serde_json::from_str(data)?;
+                self.transaction(|mirror| mirror.save_customer(&customer))?;
+                Ok(true)
+            },
+            _ => {
+                debug!("Ignoring webhook event {}", event);
+                Ok(false)
+            },
+        }
+    }
+
+    // Run `f` in a transaction, rolling back if it fails
+    fn transaction<T, F>(&mut self, f: F) -> Result<T, HelpScoutError>
+        where F: FnOnce(&Mirror) -> Result<T, HelpScoutError>
+    {
+        self.conn.execute_batch("BEGIN")?;
+        match f(self) {
+            Ok(value) => {
+                self.conn.execute_batch("COMMIT")?;
+                Ok(value)
+            },
+            Err(e) => {
+                if let Err(rollback) = self.conn.execute_batch("ROLLBACK") {
+                    warn!("Rolling back mirror transaction failed: {}", rollback);
+                }
+                Err(e)
+            },
+        }
+    }
+}
+
+// Only the id is needed from a deleted conversation
+#[derive(Debug, Deserialize)]
+struct DeletedConversation {
+    id: ConversationId,
+}
@@ -0,0 +1,123 @@
+// Tables for the mirror. Dates are stored as RFC 3339 text in UTC, so they
+// sort and compare correctly as strings. Every statement is idempotent so
+// opening an existing mirror is harmless.
+pub const SCHEMA: &'static str = "
+CREATE TABLE IF NOT EXISTS mailboxes (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    email TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    modified_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS folders (
+    id INTEGER PRIMARY KEY,
+    mailbox_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    type TEXT NOT NULL,
+    user_id INTEGER NOT NULL,
+    total_count INTEGER NOT NULL,
+    active_count INTEGER NOT NULL,
+    modified_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS conversations (
+    id INTEGER PRIMARY KEY,
+    number INTEGER NOT NULL,
+    mailbox_id INTEGER NOT NULL,
+    folder_id INTEGER NOT NULL,
+    type TEXT NOT NULL,
+    status TEXT NOT NULL,
+    is_draft INTEGER NOT NULL,
+    subject TEXT,
+    preview TEXT,
+    customer_id INTEGER NOT NULL,
+    owner_id INTEGER,
+    thread_count INTEGER NOT NULL,
+    source_type TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    modified_at TEXT,
+    closed_at TEXT
+);
+CREATE INDEX IF NOT EXISTS conversations_mailbox ON conversations (mailbox_id, modified_at);
+CREATE INDEX IF NOT EXISTS conversations_customer ON conversations (customer_id);
+
+CREATE TABLE IF NOT EXISTS conversation_tags (
+    conversation_id INTEGER NOT NULL,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (conversation_id, tag)
+);
+
+CREATE TABLE IF NOT EXISTS threads (
+    id INTEGER PRIMARY KEY,
+    conversation_id INTEGER NOT NULL,
+    type TEXT NOT NULL,
+    status TEXT NOT NULL,
+    state TEXT,
+    created_by_id INTEGER,
+    created_by_customer INTEGER NOT NULL,
+    assigned_to_id INTEGER,
+    body TEXT,
+    created_at TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS threads_conversation ON threads (conversation_id, created_at);
+
+CREATE TABLE IF NOT EXISTS customers (
+    id INTEGER PRIMARY KEY,
+    first_name TEXT,
+    last_name TEXT,
+    organization TEXT,
+    job_title TEXT,
+    location TEXT,
+    created_at TEXT NOT NULL,
+    modified_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS customer_emails (
+    customer_id INTEGER NOT NULL,
+    email TEXT NOT NULL,
+    location TEXT NOT NULL,
+    PRIMARY KEY (customer_id, email)
+);
+
+CREATE TABLE IF NOT EXISTS users (
+    id INTEGER PRIMARY KEY,
+    first_name TEXT NOT NULL,
+    last_name TEXT NOT NULL,
+    email TEXT NOT NULL,
+    role TEXT NOT NULL,
+    timezone TEXT NOT NULL,
+    type TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    modified_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS teams (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    modified_at TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS team_members (
+    team_id INTEGER NOT NULL,
+    user_id INTEGER NOT NULL,
+    PRIMARY KEY (team_id, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS tags (
+    id INTEGER PRIMARY KEY,
+    tag TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    color TEXT NOT NULL,
+    count INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    modified_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS sync_cursors (
+    resource TEXT PRIMARY KEY,
+    synced_at TEXT NOT NULL
+);
+";
@@ -0,0 +1,200 @@
+use chrono::{DateTime, Utc};
+use rusqlite::{OptionalExtension, NO_PARAMS};
+
+use api::conversations::{Conversation, ConversationThread};
+use api::customers::Customer;
+use api::mailboxes::{Folder, Mailbox};
+use api::tags::Tag;
+use api::users::User;
+use error::HelpScoutError;
+use id::{ConversationId, CustomerId, MailboxId, UserId};
+use super::Mirror;
+
+fn date(d: &DateTime<Utc>) -> String {
+    d.to_rfc3339()
+}
+
+fn optional_date(d: &Option<DateTime<Utc>>) -> Option<String> {
+    d.as_ref().map(date)
+}
+
+/// Writing single records. `sync` calls these, but they're also useful for
+/// records that arrive some other way.
+impl Mirror {
+    pub fn save_mailbox(&self, mailbox: &Mailbox) -> Result<(), HelpScoutError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO mailboxes (id, name, slug, email, created_at, modified_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![mailbox.id.0, mailbox.name, mailbox.slug, mailbox.email, date(&mailbox.created_at), date(&mailbox.modified_at)],
+        )?;
+        Ok(())
+    }
+
+    pub fn save_folder(&self, mailbox: MailboxId, folder: &Folder) -> Result<(), HelpScoutError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO folders (id, mailbox_id, name, type, user_id, total_count, active_count, modified_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![folder.id.0, mailbox.0, folder.name, folder.folder_type, folder.user_id.0,
+                folder.total_count, folder.active_count, date(&folder.modified_at)],
+        )?;
+        Ok(())
+    }
+
+    /// Save a conversation and its tags. Its threads are replaced too when
+    /// it has them, which conversations from a list don't.
+    pub fn save_conversation(&self, conversation: &Conversation) -> Result<(), HelpScoutError> {
+        let id = conversation.id.0;
+        self.conn.execute(
+            "INSERT OR REPLACE INTO conversations (id, number, mailbox_id, folder_id, type, status, is_draft, subject,
+                preview, customer_id, owner_id, thread_count, source_type, created_at, modified_at, closed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+            params![
+                id,
+                conversation.number,
+                conversation.mailbox.id.0,
+                conversation.folder_id.0,
+                conversation.conversation_type.as_str(),
+                conversation.status.as_str(),
+                conversation.is_draft,
+                conversation.subject,
+                conversation.preview,
+                conversation.customer.id,
+                conversation.owner.as_ref().map(|o| o.id),
+                conversation.thread_count,
+                conversation.source.conversation_source_type.as_str(),
+                date(&conversation.created_at),
+                optional_date(&conversation.user_modified_at.or(conversation.modified_at)),
+                optional_date(&conversation.closed_at),
+            ],
+        )?;
+
+        self.conn.execute("DELETE FROM conversation_tags WHERE conversation_id = ?1", params![id])?;
+        for tag in &conversation.tags {
+            self.conn.execute("INSERT OR IGNORE INTO conversation_tags (conversation_id, tag) VALUES (?1, ?2)", params![id, tag])?;
+        }
+
+        if let Some(ref threads) = conversation.threads {
+            self.conn.execute("DELETE FROM threads WHERE conversation_id = ?1", params![id])?;
+            for thread in threads {
+                self.save_thread(conversation.id, thread)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn save_thread(&self, conversation: ConversationId, thread: &ConversationThread) -> Result<(), HelpScoutError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO threads (id, conversation_id, type, status, state, created_by_id,
+                created_by_customer, assigned_to_id, body, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                thread.id.0,
+                conversation.0,
+                thread.conversation_thread_type.as_str(),
+                thread.status.as_str(),
+                thread.state.as_ref().map(|s| s.as_str()),
+                thread.created_by.as_ref().map(|p| p.id),
+                thread.created_by_customer,
+                thread.assigned_to.as_ref().map(|p| p.id),
+                thread.body,
+                date(&thread.created_at),
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn delete_conversation(&self, id: ConversationId) -> Result<(), HelpScoutError> {
+        self.conn.execute("DELETE FROM threads WHERE conversation_id = ?1", params![id.0])?;
+        self.conn.execute("DELETE FROM conversation_tags WHERE conversation_id = ?1", params![id.0])?;
+        self.conn.execute("DELETE FROM conversations WHERE id = ?1", params![id.0])?;
+        Ok(())
+    }
+
+    /// Save a customer, and their emails when they have them
+    pub fn save_customer(&self, customer: &Customer) -> Result<(), HelpScoutError> {
+        let id = customer.id.0;
+        self.conn.execute(
+            "INSERT OR REPLACE INTO customers (id, first_name, last_name, organization, job_title, location, created_at, modified_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![id, customer.first_name, customer.last_name, customer.organization, customer.job_title,
+                customer.location, date(&customer.created_at), optional_date(&customer.modified_at)],
+        )?;
+
+        if let Some(ref emails) = customer.emails {
+            self.conn.execute("DELETE FROM customer_emails WHERE customer_id = ?1", params![id])?;
+            for email in emails {
+                self.conn.execute(
+                    "INSERT OR REPLACE INTO customer_emails (customer_id, email, location) VALUES (?1, ?2, ?3)",
+                    params![id, email.value, email.location.as_str()],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn delete_customer(&self, id: CustomerId) -> Result<(), HelpScoutError> {
+        self.conn.execute("DELETE FROM customer_emails WHERE customer_id = ?1", params![id.0])?;
+        self.conn.execute("DELETE FROM customers WHERE id = ?1", params![id.0])?;
+        Ok(())
+    }
+
+    pub fn save_user(&self, user: &User) -> Result<(), HelpScoutError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO users (id, first_name, last_name, email, role, timezone, type, created_at, modified_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![user.id.0, user.first_name, user.last_name, user.email, user.role, user.timezone,
+                user.user_type.as_str(), date(&user.created_at), date(&user.modified_at)],
+        )?;
+        Ok(())
+    }
+
+    /// Save a team, as listed by the teams endpoint, and who's in it
+    pub fn save_team(&self, team: &User, members: &[UserId]) -> Result<(), HelpScoutError> {
+        let id = team.id.0;
+        self.conn.execute(
+            "INSERT OR REPLACE INTO teams (id, name, created_at, modified_at) VALUES (?1, ?2, ?3, ?4)",
+            params![id, team.first_name, date(&team.created_at), date(&team.modified_at)],
+        )?;
+        self.conn.execute("DELETE FROM team_members WHERE team_id = ?1", params![id])?;
+        for member in members {
+            self.conn.execute("INSERT OR IGNORE INTO team_members (team_id, user_id) VALUES (?1, ?2)", params![id, member.0])?;
+        }
+        Ok(())
+    }
+
+    pub fn save_tag(&self, tag: &Tag) -> Result<(), HelpScoutError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO tags (id, tag, slug, color, count, created_at, modified_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![tag.id, tag.tag, tag.slug, tag.color, tag.count, date(&tag.created_at), optional_date(&tag.modified_at)],
+        )?;
+        Ok(())
+    }
+
+    /// When `resource` was last synced up to
+    pub fn cursor(&self, resource: &str) -> Result<Option<DateTime<Utc>>, HelpScoutError> {
+        let synced_at: Option<String> = self.conn
+            .query_row("SELECT synced_at FROM sync_cursors WHERE resource = ?1", params![resource], |row| row.get(0))
+            .optional()?;
+        match synced_at {
+            Some(s) => DateTime::parse_from_rfc3339(&s)
+                .map(|d| Some(d.with_timezone(&Utc)))
+                .map_err(|e| HelpScoutError::DatabaseError(format!("Bad cursor for {}: {}", resource, e))),
+            None => Ok(None),
+        }
+    }
+
+    pub fn set_cursor(&self, resource: &str, synced_at: DateTime<Utc>) -> Result<(), HelpScoutError> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sync_cursors (resource, synced_at) VALUES (?1, ?2)",
+            params![resource, date(&synced_at)],
+        )?;
+        Ok(())
+    }
+
+    /// Forget every cursor, so the next sync fetches everything again
+    pub fn reset_cursors(&self) -> Result<(), HelpScoutError> {
+        self.conn.execute("DELETE FROM sync_cursors", NO_PARAMS)?;
+        Ok(())
+    }
+}
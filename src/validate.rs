@@ -0,0 +1,87 @@
+//! Client side checks for request bodies.
+//!
+//! These mirror the constraints documented by HelpScout so that bad input is
+//! caught before a request is made. The server remains the source of truth.
+use reqwest::Url;
+use serde_json::Value;
+
+use client::FieldError;
+
+/// Collects problems found while validating a request body.
+#[derive(Debug, Default)]
+pub(crate) struct Problems {
+    errors: Vec<FieldError>,
+}
+
+impl Problems {
+    pub fn new() -> Problems {
+        Problems::default()
+    }
+
+    pub fn push(&mut self, path: &str, message: &str, value: Option<&str>) {
+        self.errors.push(FieldError {
+            path: path.into(),
+            message: message.into(),
+            rejected_value: value.map(|v| Value::String(v.into())),
+        });
+    }
+
+    /// Value must be present and no longer than `max` characters.
+    pub fn required(&mut self, path: &str, value: &str, max: usize) {
+        if value.trim().is_empty() {
+            self.push(path, "is required", None);
+        } else {
+            self.max_len(path, value, max);
+        }
+    }
+
+    pub fn max_len(&mut self, path: &str, value: &str, max: usize) {
+        if value.chars().count() > max {
+            self.push(path, &format!("must be at most {} characters", max), Some(value));
+        }
+    }
+
+    pub fn optional_max_len(&mut self, path: &str, value: &Option<String>, max: usize) {
+        if let Some(ref v) = *value {
+            self.max_len(path, v, max);
+        }
+    }
+
+    pub fn email(&mut self, path: &str, value: &str) {
+        if !is_email(value) {
+            self.push(path, "is not a valid email address", Some(value));
+        }
+    }
+
+    pub fn url(&mut self, path: &str, value: &str) {
+        if Url::parse(value).is_err() {
+            self.push(path, "is not a valid URL", Some(value));
+        }
+    }
+
+    pub fn into_result(self) -> Result<(), Vec<FieldError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+// Deliberately loose: one `@`, something on either side, a dot in the domain
+// and no whitespace. Anything stricter rejects addresses HelpScout accepts.
+fn is_email(value: &str) -> bool {
+    if value.chars().any(|c| c.is_whitespace()) {
+        return false;
+    }
+    let mut parts = value.split('@');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(local), Some(domain), None) => {
+            !local.is_empty()
+                && domain.contains('.')
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,87 @@
+extern crate helpscout;
+extern crate chrono;
+
+#[cfg(test)]
+mod analysis {
+    use chrono::prelude::*;
+
+    use super::helpscout::api::reports::conversations::{BusyTimeStatistics, ConvReportViewByType, ReceivedMessagesStatistics};
+    use super::helpscout::api::reports::analysis::heatmap::Heatmap;
+    use super::helpscout::api::reports::analysis::series::{self, Point, moving_average, resample};
+
+    fn busy(day: i32, hour: i32, count: i32) -> BusyTimeStatistics {
+        BusyTimeStatistics { day: day, hour: hour, count: count }
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn pivots_and_normalizes_busy_times() {
+        let heatmap = Heatmap::from_stats(&[busy(1, 9, 20), busy(7, 23, 4), busy(8, 0, 100)]);
+        assert_eq!(heatmap.get(Weekday::Mon, 9), Some(20.0));
+        assert_eq!(heatmap.get(Weekday::Sun, 23), Some(4.0));
+        assert_eq!(heatmap.get(Weekday::Sun, 24), None);
+        assert_eq!(heatmap.max(), 20.0);
+
+        let weekly = heatmap.per_week(utc("2018-01-01T00:00:00Z"), utc("2018-01-29T00:00:00Z"));
+        assert_eq!(weekly.get(Weekday::Mon, 9), Some(5.0));
+        assert_eq!(weekly.get(Weekday::Sun, 23), Some(1.0));
+    }
+
+    #[test]
+    fn renders_ascii_and_svg() {
+        let heatmap = Heatmap::from_stats(&[busy(1, 0, 10), busy(2, 1, 1)]);
+        let ascii = heatmap.to_ascii();
+        let lines: Vec<&str> = ascii.lines().collect();
+
+        assert_eq!(lines.len(), 8);
+        assert!(lines[1].starts_with("Mon @"));
+        assert!(lines[2].starts_with("Tue  ."));
+
+        let svg = heatmap.to_svg(10);
+        assert!(svg.starts_with("<svg"));
+        assert_eq!(svg.matches("<rect").count(), 7 * 24);
+    }
+
+    #[test]
+    fn suggests_non_overlapping_staffing_windows() {
+        let heatmap = Heatmap::from_stats(&[
+            busy(1, 9, 10), busy(1, 10, 10), busy(1, 11, 2),
+            busy(3, 14, 8),
+        ]);
+        let windows = heatmap.staffing_windows(2, 5);
+
+        assert_eq!(windows.len(), 3);
+        assert_eq!((windows[0].day, windows[0].start_hour, windows[0].end_hour), (Weekday::Mon, 9, 11));
+        assert_eq!(windows[0].load, 20.0);
+        assert_eq!(windows[1].day, Weekday::Wed);
+        assert_eq!((windows[2].day, windows[2].start_hour), (Weekday::Mon, 11));
+    }
+
+    #[test]
+    fn resamples_and_smooths_series() {
+        let received = vec![
+            ReceivedMessagesStatistics { date: utc("2018-01-29T00:00:00Z"), messages: 2 },
+            ReceivedMessagesStatistics { date: utc("2018-01-31T00:00:00Z"), messages: 4 },
+            ReceivedMessagesStatistics { date: utc("2018-02-05T00:00:00Z"), messages: 6 },
+        ];
+        let daily = series::points(&received);
+
+        let weekly = resample(&daily, &ConvReportViewByType::Week);
+        assert_eq!(weekly, vec![
+            Point { date: utc("2018-01-29T00:00:00Z"), value: 6.0 },
+            Point { date: utc("2018-02-05T00:00:00Z"), value: 6.0 },
+        ]);
+
+        let monthly = resample(&daily, &ConvReportViewByType::Month);
+        assert_eq!(monthly[0], Point { date: utc("2018-01-01T00:00:00Z"), value: 6.0 });
+        assert_eq!(monthly[1], Point { date: utc("2018-02-01T00:00:00Z"), value: 6.0 });
+
+        let average = moving_average(&daily, 2);
+        assert_eq!(average.len(), 2);
+        assert_eq!(average[0].value, 3.0);
+        assert_eq!(average[1], Point { date: utc("2018-02-05T00:00:00Z"), value: 5.0 });
+    }
+}
@@ -0,0 +1,116 @@
+extern crate helpscout;
+extern crate chrono;
+
+#[cfg(test)]
+mod calendar {
+    use chrono::prelude::*;
+    use chrono::Duration;
+
+    use super::helpscout::calendar::BusinessCalendar;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // 9 to 5 in New York, with Monday the 8th off
+    fn office() -> BusinessCalendar {
+        BusinessCalendar::new(FixedOffset::west_opt(5 * 3600).unwrap())
+            .weekdays(time(9), time(17))
+            .holiday(date(2018, 1, 8))
+    }
+
+    #[test]
+    fn skips_nights_weekends_and_holidays() {
+        let calendar = office();
+        // Friday 4pm local plus two hours is Tuesday 10am
+        assert_eq!(calendar.add(utc("2018-01-05T21:00:00Z"), Duration::hours(2)), Some(utc("2018-01-09T15:00:00Z")));
+        assert_eq!(calendar.between(utc("2018-01-05T21:00:00Z"), utc("2018-01-09T15:00:00Z")), Duration::hours(2));
+        assert_eq!(calendar.between(utc("2018-01-06T12:00:00Z"), utc("2018-01-07T12:00:00Z")), Duration::zero());
+        assert_eq!(calendar.between(utc("2018-01-09T15:00:00Z"), utc("2018-01-05T21:00:00Z")), Duration::zero());
+
+        // Starting outside hours waits for the next opening
+        assert_eq!(calendar.add(utc("2018-01-06T12:00:00Z"), Duration::minutes(30)), Some(utc("2018-01-09T14:30:00Z")));
+        assert_eq!(calendar.add(utc("2018-01-06T12:00:00Z"), Duration::zero()), Some(utc("2018-01-06T12:00:00Z")));
+    }
+
+    #[test]
+    fn is_open() {
+        let calendar = office();
+        assert!(calendar.is_open(utc("2018-01-09T14:00:00Z")));
+        assert!(!calendar.is_open(utc("2018-01-09T22:00:00Z")));
+        assert!(!calendar.is_open(utc("2018-01-08T15:00:00Z")));
+        assert!(!calendar.is_open(utc("2018-01-06T15:00:00Z")));
+    }
+
+    #[test]
+    fn split_shifts_and_late_closing() {
+        let calendar = BusinessCalendar::new(Utc)
+            .hours(Weekday::Mon, time(8), time(12))
+            .hours(Weekday::Mon, time(13), time(17))
+            .hours(Weekday::Tue, time(20), time(0));
+
+        assert_eq!(calendar.between(utc("2018-01-08T00:00:00Z"), utc("2018-01-10T00:00:00Z")), Duration::hours(12));
+        assert_eq!(calendar.add(utc("2018-01-08T11:00:00Z"), Duration::hours(2)), Some(utc("2018-01-08T14:00:00Z")));
+        assert_eq!(calendar.add(utc("2018-01-08T16:00:00Z"), Duration::hours(4)), Some(utc("2018-01-09T23:00:00Z")));
+        assert!(calendar.is_open(utc("2018-01-09T23:59:00Z")));
+        assert!(!calendar.is_open(utc("2018-01-08T12:30:00Z")));
+    }
+
+    #[test]
+    fn overnight_shifts_run_into_the_next_day() {
+        let calendar = BusinessCalendar::new(Utc).hours(Weekday::Mon, time(22), time(6));
+
+        assert!(calendar.is_open(utc("2018-01-08T23:00:00Z")));
+        assert!(calendar.is_open(utc("2018-01-09T05:00:00Z")));
+        assert!(!calendar.is_open(utc("2018-01-09T07:00:00Z")));
+        assert_eq!(calendar.between(utc("2018-01-08T00:00:00Z"), utc("2018-01-10T00:00:00Z")), Duration::hours(8));
+        assert_eq!(calendar.add(utc("2018-01-08T21:00:00Z"), Duration::hours(3)), Some(utc("2018-01-09T01:00:00Z")));
+    }
+
+    #[test]
+    fn no_working_hours_never_adds_up() {
+        let calendar = BusinessCalendar::new(Utc);
+        assert_eq!(calendar.add(utc("2018-01-08T21:00:00Z"), Duration::hours(1)), None);
+        assert_eq!(calendar.add(utc("2018-01-08T21:00:00Z"), Duration::zero()), Some(utc("2018-01-08T21:00:00Z")));
+    }
+
+    #[test]
+    fn always_open() {
+        let calendar = BusinessCalendar::always();
+        assert_eq!(calendar.add(utc("2018-01-05T21:00:00Z"), Duration::hours(30)), Some(utc("2018-01-07T03:00:00Z")));
+        assert_eq!(calendar.between(utc("2018-01-05T21:00:00Z"), utc("2018-01-07T03:00:00Z")), Duration::hours(30));
+    }
+
+    #[test]
+    fn imports_holidays_from_ics() {
+        let calendar = BusinessCalendar::new(Utc)
+            .weekdays(time(9), time(17))
+            .holiday(date(2018, 12, 25))
+            .holidays_from_ics(include_str!("fixtures/holidays.ics"))
+            .unwrap();
+
+        assert_eq!(calendar.holidays(), &[
+            date(2018, 1, 1),
+            date(2018, 7, 4),
+            date(2018, 12, 24),
+            date(2018, 12, 25),
+            date(2018, 12, 26),
+        ]);
+        // Friday the 21st, then nothing until Thursday the 27th
+        assert_eq!(calendar.add(utc("2018-12-21T16:00:00Z"), Duration::hours(2)), Some(utc("2018-12-27T10:00:00Z")));
+    }
+
+    #[test]
+    fn rejects_bad_ics_dates() {
+        let ics = "BEGIN:VCALENDAR\nBEGIN:VEVENT\nDTSTART;VALUE=DATE:christmas\nEND:VEVENT\nEND:VCALENDAR\n";
+        assert!(BusinessCalendar::always().holidays_from_ics(ics).is_err());
+    }
+}
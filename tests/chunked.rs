@@ -0,0 +1,86 @@
+extern crate helpscout;
+extern crate chrono;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod chunked {
+    use chrono::prelude::*;
+    use chrono::Duration;
+    use serde_json::{self, Value};
+
+    use super::helpscout::{ConversationId, HelpScoutError};
+    use super::helpscout::api::report;
+    use super::helpscout::api::reports::conversations::ConversationsReportBuilder;
+    use super::helpscout::api::reports::conversations::chunked::{ChunkedDrillDown, split_range};
+    use super::helpscout::api::reports::conversations::drill_down::DrillDownConversationsReport;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn page(ids: &[i64], page: i32, pages: i32) -> DrillDownConversationsReport {
+        let fixture: Value = serde_json::from_str(include_str!("fixtures/report_fields_drilldown.json")).unwrap();
+        let template = fixture["conversations"]["results"][0].clone();
+        let results: Vec<Value> = ids.iter().map(|&id| {
+            let mut conversation = template.clone();
+            conversation["id"] = json!(id);
+            conversation
+        }).collect();
+
+        serde_json::from_value(json!({
+            "conversations": {"page": page, "pages": pages, "count": ids.len(), "results": results}
+        })).unwrap()
+    }
+
+    #[test]
+    fn splits_ranges_into_windows() {
+        let windows = split_range(utc("2018-01-01T00:00:00Z"), utc("2018-03-01T00:00:00Z"), Duration::days(30));
+
+        assert_eq!(windows, vec![
+            (utc("2018-01-01T00:00:00Z"), utc("2018-01-31T00:00:00Z")),
+            (utc("2018-01-31T00:00:00Z"), utc("2018-03-01T00:00:00Z")),
+        ]);
+        assert!(split_range(utc("2018-01-01T00:00:00Z"), utc("2018-03-01T00:00:00Z"), Duration::zero()).is_empty());
+    }
+
+    #[test]
+    fn pages_through_windows_and_dedupes() {
+        let mut requests = Vec::new();
+        let builder = report(utc("2018-01-01T00:00:00Z"), utc("2018-01-21T00:00:00Z")).conversations();
+
+        let ids: Vec<ConversationId> = {
+            let source = |params: &ConversationsReportBuilder| {
+                requests.push((params.start, params.page, params.rows));
+                Ok(match (params.start.day(), params.page) {
+                    (1, Some(1)) => page(&[1, 2], 1, 2),
+                    (1, Some(2)) => page(&[3], 2, 2),
+                    _ => page(&[3, 4], 1, 1),
+                })
+            };
+            ChunkedDrillDown::new(source, builder, Duration::days(10))
+                .map(|c| c.unwrap().id)
+                .collect()
+        };
+
+        assert_eq!(ids, vec![ConversationId(1), ConversationId(2), ConversationId(3), ConversationId(4)]);
+        assert_eq!(requests, vec![
+            (utc("2018-01-01T00:00:00Z"), Some(1), Some(50)),
+            (utc("2018-01-01T00:00:00Z"), Some(2), Some(50)),
+            (utc("2018-01-11T00:00:00Z"), Some(1), Some(50)),
+        ]);
+    }
+
+    #[test]
+    fn stops_after_an_error() {
+        let builder = report(utc("2018-01-01T00:00:00Z"), utc("2018-01-21T00:00:00Z")).conversations();
+        let source = |_: &ConversationsReportBuilder| -> Result<DrillDownConversationsReport, HelpScoutError> {
+            Err(HelpScoutError::ServiceUnavailable)
+        };
+
+        let results: Vec<_> = ChunkedDrillDown::new(source, builder, Duration::days(10)).collect();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].is_err());
+    }
+}
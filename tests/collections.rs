@@ -0,0 +1,50 @@
+extern crate helpscout;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod collections {
+    use serde_json::{self, Value};
+
+    use super::helpscout::{Collection, HelpScoutError};
+    use super::helpscout::api::conversations::Conversation;
+
+    fn fixture_with_bad_item() -> Value {
+        let mut page: Value = serde_json::from_str(include_str!("fixtures/conversations.json")).unwrap();
+        page["items"][0]["createdAt"] = json!("not a date");
+        page
+    }
+
+    #[test]
+    fn strict_parsing_fails_the_page() {
+        let res = serde_json::from_value::<Collection<Conversation>>(fixture_with_bad_item());
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn lenient_parsing_skips_bad_items() {
+        let page = fixture_with_bad_item();
+        let bad = page["items"][0].clone();
+        let conversations: Collection<Conversation> = Collection::from_value_lenient(page).expect("envelope to parse");
+
+        assert_eq!(conversations.count, 2);
+        assert_eq!(conversations.items.len(), 1);
+        assert_eq!(conversations.items[0].number, 350);
+
+        assert_eq!(conversations.skipped.len(), 1);
+        let skipped = &conversations.skipped[0];
+        assert_eq!(skipped.index, 0);
+        assert_eq!(skipped.value, bad);
+        match skipped.error {
+            HelpScoutError::JsonParseError(_) => {},
+            ref e => panic!("unexpected error {:?}", e),
+        }
+    }
+
+    #[test]
+    fn lenient_parsing_still_requires_the_envelope() {
+        let res = Collection::<Conversation>::from_value_lenient(json!({"items": []}));
+        assert!(res.is_err());
+    }
+}
@@ -20,7 +20,7 @@ mod conversations {
         let api_key: String = env::var("API_KEY").expect("to have API_KEY set");
 
         let c = Client::new(&api_key);
-        let mailboxes = mailboxes::list(&c).expect("Grab mailboxes for testing");
+        let mailboxes = mailboxes::list(&c, None).expect("Grab mailboxes for testing");
         let conversations = conversations::list(&c, mailboxes.items[0].id).expect("Conversations to be listed");
 
         //println!("{:?}", conversations);
@@ -43,7 +43,7 @@ mod conversations {
         let api_key: String = env::var("API_KEY").expect("to have API_KEY set");
         let c = Client::new(&api_key);
 
-        let mailboxes = mailboxes::list(&c).expect("Grab mailboxes for testing");
+        let mailboxes = mailboxes::list(&c, None).expect("Grab mailboxes for testing");
         let mailbox_ref = MailboxRef{id: mailboxes.items[0].id, name: "".into()};
         let users = users::list(&c, None, None).expect("Grab users for testing");
         let customers = customers::list().send(&c).expect("Grab customers for testing");
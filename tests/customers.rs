@@ -26,7 +26,7 @@ mod customers {
 
         let customer = customers::get(&c, customers.items[0].id).expect("To get a customer");
         //println!("{:?}", customer);
-        assert!(customer.item.id > 0);
+        assert!(customer.item.id.0 > 0);
 
 
     }
@@ -39,7 +39,7 @@ mod customers {
 
         let c = Client::new(&API_KEY);
 
-        let mailboxes = mailboxes::list(&c).expect("Grab mailboxes for testing");
+        let mailboxes = mailboxes::list(&c, None).expect("Grab mailboxes for testing");
         let customers = super::helpscout::api::customers().list_by_mailbox(&c, mailboxes.items[0].id).expect("Customers for the mailbox to be listed");
         println!("{:?}", customers.items[0]);
         assert!(customers.items.len() > 0);
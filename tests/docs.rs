@@ -0,0 +1,126 @@
+extern crate helpscout;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod docs {
+    use serde_json::{self, Value};
+
+    use super::helpscout::{Collection, MailboxId, UserId};
+    use super::helpscout::docs::Visibility;
+    use super::helpscout::docs::articles::{ArticleRef, ArticleStatus, NewArticle};
+    use super::helpscout::docs::categories::{ArticleSort, Category, NewCategory};
+    use super::helpscout::docs::collections::{DocsCollection, NewDocsCollection};
+    use super::helpscout::docs::redirects::{Redirect, RedirectType};
+    use super::helpscout::docs::sites::{NewSite, Site};
+
+    #[test]
+    fn parses_article_list() {
+        let res: Value = serde_json::from_str(include_str!("fixtures/docs_articles.json")).unwrap();
+        let articles: Collection<ArticleRef> = serde_json::from_value(res["articles"].clone()).expect("articles to parse");
+
+        assert_eq!(articles.items.len(), 1);
+        let article = &articles.items[0];
+        assert_eq!(article.id, "5214c77c45667acd25394b51");
+        assert_eq!(article.status, ArticleStatus::Published);
+        assert_eq!(article.created_by, UserId(73423));
+    }
+
+    #[test]
+    fn parses_single_site() {
+        let res: Value = serde_json::from_str(include_str!("fixtures/docs_site.json")).unwrap();
+        let site: Site = serde_json::from_value(res["site"].clone()).expect("site to parse");
+
+        assert_eq!(site.id, "52404efc3e3e9bd67a3dc68a");
+        assert_eq!(site.sub_domain, "helpscout");
+        assert_eq!(site.cname, Some("docs.helpscout.net".into()));
+        assert!(site.has_contact_form);
+        assert_eq!(site.mailbox_id, Some(MailboxId(1234)));
+        assert_eq!(site.fav_icon_url, None);
+        assert_eq!(site.updated_by, Some(UserId(73423)));
+    }
+
+    #[test]
+    fn parses_collection_list() {
+        let res: Value = serde_json::from_str(include_str!("fixtures/docs_collections.json")).unwrap();
+        let collections: Collection<DocsCollection> = serde_json::from_value(res["collections"].clone()).expect("collections to parse");
+
+        assert_eq!((collections.page, collections.pages, collections.count), (1, 1, 2));
+        let guides = &collections.items[0];
+        assert_eq!(guides.slug, "product-guides");
+        assert_eq!(guides.visibility, Visibility::Public);
+        assert_eq!(guides.published_article_count, Some(2));
+
+        let internal = &collections.items[1];
+        assert_eq!(internal.visibility, Visibility::Private);
+        assert_eq!(internal.public_url, None);
+        assert_eq!(internal.updated_at, None);
+    }
+
+    #[test]
+    fn parses_category_list() {
+        let res: Value = serde_json::from_str(include_str!("fixtures/docs_categories.json")).unwrap();
+        let categories: Collection<Category> = serde_json::from_value(res["categories"].clone()).expect("categories to parse");
+
+        assert_eq!(categories.items.len(), 2);
+        let basics = &categories.items[0];
+        assert_eq!(basics.collection_id, "5214c77d45667acd25394b52");
+        assert_eq!(basics.default_sort, Some(ArticleSort::Popularity));
+        assert_eq!(categories.items[1].description, Some("Plans and invoices".into()));
+    }
+
+    #[test]
+    fn parses_redirect_list() {
+        let res: Value = serde_json::from_str(include_str!("fixtures/docs_redirects.json")).unwrap();
+        let redirects: Collection<Redirect> = serde_json::from_value(res["redirects"].clone()).expect("redirects to parse");
+
+        assert_eq!(redirects.items.len(), 2);
+        assert_eq!(redirects.items[0].url_mapping, "/old-getting-started");
+        assert_eq!(redirects.items[0].redirect_type, Some(RedirectType::Article));
+        assert_eq!(redirects.items[1].redirect_type, Some(RedirectType::Url));
+        assert_eq!(redirects.items[1].redirect, "https://www.helpscout.net/pricing/");
+    }
+
+    #[test]
+    fn update_body_leaves_out_unset_fields() {
+        let mut article = NewArticle::new("5214c77d45667acd25394b52", "Getting Started", "<p>Hello</p>");
+        article.status(ArticleStatus::NotPublished).slug("getting-started");
+
+        let body = serde_json::to_value(&article).unwrap();
+        assert_eq!(body, json!({
+            "collectionId": "5214c77d45667acd25394b52",
+            "name": "Getting Started",
+            "text": "<p>Hello</p>",
+            "status": "notpublished",
+            "slug": "getting-started"
+        }));
+    }
+
+    #[test]
+    fn site_collection_and_category_bodies_leave_out_unset_fields() {
+        let mut site = NewSite::new("helpscout", "Help Scout");
+        site.cname("docs.helpscout.net");
+        assert_eq!(serde_json::to_value(&site).unwrap(), json!({
+            "subDomain": "helpscout",
+            "title": "Help Scout",
+            "cname": "docs.helpscout.net"
+        }));
+
+        let mut collection = NewDocsCollection::new("52404efc3e3e9bd67a3dc68a", "Product Guides");
+        collection.visibility(Visibility::Private);
+        assert_eq!(serde_json::to_value(&collection).unwrap(), json!({
+            "siteId": "52404efc3e3e9bd67a3dc68a",
+            "name": "Product Guides",
+            "visibility": "private"
+        }));
+
+        let mut category = NewCategory::new("5214c77d45667acd25394b52", "Basics");
+        category.order(2);
+        assert_eq!(serde_json::to_value(&category).unwrap(), json!({
+            "collectionId": "5214c77d45667acd25394b52",
+            "name": "Basics",
+            "order": 2
+        }));
+    }
+}
@@ -0,0 +1,64 @@
+extern crate helpscout;
+
+extern crate serde_json;
+
+#[cfg(test)]
+mod enums {
+    use serde_json;
+
+    use super::helpscout::{Collection, ConversationId};
+    use super::helpscout::api::person::PersonType;
+    use super::helpscout::api::users::UserType;
+    use super::helpscout::api::mailboxes::CustomFieldType;
+    use super::helpscout::api::customers::{CustomerGender, CustomerPhotoType, CustomerSocialProfileType};
+    use super::helpscout::api::conversations::{Conversation, ConversationType, ConversationStatus,
+        ConversationSourceType, ConversationThreadType, ConversationActionType, ConversationThreadState};
+
+    #[test]
+    fn unknown_values_do_not_abort_collection_parsing() {
+        let conversations: Collection<Conversation> = serde_json::from_str(include_str!("fixtures/conversations.json"))
+            .expect("conversations to parse");
+        assert_eq!(conversations.items.len(), 2);
+
+        let known = &conversations.items[0];
+        assert_eq!(known.id, ConversationId(2391938111));
+        assert_eq!(known.conversation_type, ConversationType::Email);
+        assert_eq!(known.source.conversation_source_type, ConversationSourceType::Email);
+
+        let unknown = &conversations.items[1];
+        assert_eq!(unknown.conversation_type, ConversationType::Unknown("messenger".into()));
+        assert_eq!(unknown.status, ConversationStatus::Unknown("snoozed".into()));
+        assert_eq!(unknown.customer.person_type, Some(PersonType::Unknown("visitor".into())));
+        assert_eq!(unknown.source.conversation_source_type, ConversationSourceType::Unknown("beacon-v2".into()));
+
+        let thread = &unknown.threads.as_ref().expect("threads")[0];
+        assert_eq!(thread.conversation_thread_type, ConversationThreadType::Unknown("beacon".into()));
+        assert_eq!(thread.action_type, Some(ConversationActionType::Unknown("autoClosed".into())));
+        assert_eq!(thread.state, Some(ConversationThreadState::Unknown("scheduled".into())));
+    }
+
+    #[test]
+    fn unknown_values_round_trip() {
+        let values = vec!["whatsapp", "twitter"];
+        for value in values {
+            let json = serde_json::to_string(value).unwrap();
+            let parsed: CustomerSocialProfileType = serde_json::from_str(&json).expect("social profile type to parse");
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+
+        let parsed: CustomFieldType = serde_json::from_str("\"DATE_TIME\"").expect("custom field type to parse");
+        assert_eq!(parsed, CustomFieldType::Unknown("DATE_TIME".into()));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), "\"DATE_TIME\"");
+    }
+
+    #[test]
+    fn known_values_keep_their_wire_names() {
+        assert_eq!(serde_json::to_string(&ConversationSourceType::EmailForward).unwrap(), "\"emailfwd\"");
+        assert_eq!(serde_json::to_string(&ConversationActionType::MovedFromMailbox).unwrap(), "\"movedFromMailbox\"");
+        assert_eq!(serde_json::to_string(&CustomFieldType::SingleLine).unwrap(), "\"SINGLE_LINE\"");
+        assert_eq!(serde_json::from_str::<UserType>("\"team\"").unwrap(), UserType::Team);
+        assert_eq!(serde_json::from_str::<CustomerGender>("\"unknown\"").unwrap(), CustomerGender::NotSpecified);
+        assert_eq!(serde_json::from_str::<CustomerPhotoType>("\"unknown\"").unwrap(), CustomerPhotoType::NotSpecified);
+        assert_eq!(serde_json::from_str::<CustomerGender>("\"other\"").unwrap(), CustomerGender::Unknown("other".into()));
+    }
+}
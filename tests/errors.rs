@@ -0,0 +1,40 @@
+extern crate helpscout;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod errors {
+    use serde_json;
+
+    use super::helpscout::{HelpScoutError, Status};
+
+    #[test]
+    fn bad_request_parses_validation_errors() {
+        let body = json!({
+            "code": 400,
+            "error": "Input could not be validated",
+            "validationErrors": [
+                {"property": "emails[0].value", "value": "not-an-email", "message": "Invalid email address"},
+                {"property": "phones[1].value", "message": "Value is required"}
+            ]
+        });
+        let status: Status = serde_json::from_value(body).expect("status to parse");
+        let err = HelpScoutError::BadRequest(status);
+
+        let fields = err.field_errors();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields[0].path, "emails[0].value");
+        assert_eq!(fields[0].message, "Invalid email address");
+        assert_eq!(fields[0].rejected_value, Some(json!("not-an-email")));
+        assert_eq!(fields[1].rejected_value, None);
+        assert!(err.to_string().contains("emails[0].value: Invalid email address"));
+    }
+
+    #[test]
+    fn status_without_validation_errors() {
+        let status: Status = serde_json::from_value(json!({"code": 404, "error": "Not found"})).expect("status to parse");
+        assert!(status.validation_errors.is_empty());
+        assert!(HelpScoutError::UserNotFound(status).field_errors().is_empty());
+    }
+}
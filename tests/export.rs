@@ -0,0 +1,100 @@
+extern crate helpscout;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod export {
+    use serde_json::{self, Value};
+
+    use super::helpscout::api::reports::conversations::BusyTimeStatistics;
+    use super::helpscout::api::reports::conversations::drill_down::DrillDownConversationsReport;
+    use super::helpscout::api::reports::productivity::overall::ProductivityReport;
+    use super::helpscout::api::reports::export::{Table, ToTable};
+
+    fn productivity() -> ProductivityReport {
+        serde_json::from_value(json!({
+            "filterTags": [],
+            "current": {
+                "startDate": "2018-01-31T19:16:57Z",
+                "endDate": "2018-02-01T19:16:57Z",
+                "totalConversations": 23,
+                "resolutionTime": 488475.313,
+                "repliesToResolve": 5.5,
+                "responseTime": 43369,
+                "firstResponseTime": 14041,
+                "resolved": 23,
+                "resolvedOnFirstReply": 7,
+                "closed": 80,
+                "repliesSent": 21,
+                "handleTime": 219,
+                "percentResolvedOnFirstReply": 40.5
+            },
+            "previous": {
+                "startDate": null,
+                "endDate": null,
+                "totalConversations": null,
+                "resolutionTime": null,
+                "repliesToResolve": null,
+                "responseTime": 0,
+                "firstResponseTime": 0,
+                "resolved": null,
+                "resolvedOnFirstReply": null,
+                "closed": 0,
+                "repliesSent": 0,
+                "handleTime": 0,
+                "percentResolvedOnFirstReply": null
+            },
+            "delta": null
+        })).expect("productivity report to parse")
+    }
+
+    #[test]
+    fn durations_are_exported_as_seconds() {
+        let table = productivity().to_table().unwrap();
+
+        assert_eq!(table.columns()[0], "period");
+        assert_eq!(table.column("period").unwrap(), vec![json!("current"), json!("previous")]);
+        assert_eq!(table.column("resolutionTime").unwrap(), vec![json!(488475.313), Value::Null]);
+        assert_eq!(table.column("firstResponseTime").unwrap(), vec![json!(14041.0), json!(0.0)]);
+    }
+
+    #[test]
+    fn writes_csv() {
+        let busy = vec![
+            BusyTimeStatistics { day: 1, hour: 9, count: 12 },
+            BusyTimeStatistics { day: 1, hour: 10, count: 7 },
+        ];
+        let mut out = Vec::new();
+        busy.to_table().unwrap().write_csv(&mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "count,day,hour\n12,1,9\n7,1,10\n");
+    }
+
+    #[test]
+    fn csv_quotes_awkward_values_and_leaves_gaps_empty() {
+        let mut table = Table::new();
+        table.push(vec![], &json!({"name": "Smith, John"})).unwrap();
+        table.push(vec![], &json!({"name": "say \"hi\"", "extra": 1})).unwrap();
+
+        let mut out = Vec::new();
+        table.write_csv(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "name,extra\n\"Smith, John\",\n\"say \"\"hi\"\"\",1\n");
+    }
+
+    #[test]
+    fn drill_down_rows_join_tags() {
+        let report: DrillDownConversationsReport = serde_json::from_str(include_str!("fixtures/report_fields_drilldown.json")).unwrap();
+        let table = report.to_table().unwrap();
+
+        assert_eq!(table.rows().len(), 1);
+        assert_eq!(table.column("tags").unwrap(), vec![json!("billing")]);
+        assert_eq!(table.column("customerIds").unwrap(), vec![json!("29418")]);
+
+        let mut out = Vec::new();
+        table.write_json_lines(&mut out).unwrap();
+        let line: Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(line["id"], json!(2391938111u64));
+        assert_eq!(line["status"], json!("closed"));
+    }
+}
@@ -0,0 +1,66 @@
+extern crate helpscout;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod happiness {
+    use serde_json;
+
+    use super::helpscout::{ConversationId, UserId};
+    use super::helpscout::api::reports::happiness::RatingScore;
+    use super::helpscout::api::reports::happiness::overall::HappinessReport;
+    use super::helpscout::api::reports::happiness::ratings::RatingsReport;
+
+    #[test]
+    fn rating_scores_map_to_numbers() {
+        assert_eq!(RatingScore::from(1), RatingScore::Great);
+        assert_eq!(RatingScore::from(2), RatingScore::Okay);
+        assert_eq!(RatingScore::from(3), RatingScore::NotGood);
+        assert_eq!(RatingScore::from(9), RatingScore::Unknown(9));
+        assert_eq!(serde_json::to_value(&RatingScore::NotGood).unwrap(), json!(3));
+    }
+
+    #[test]
+    fn parses_ratings_report() {
+        let report: RatingsReport = serde_json::from_str(include_str!("fixtures/happiness_ratings.json")).expect("ratings to parse");
+
+        assert_eq!(report.pages, 1);
+        assert_eq!(report.results.len(), 3);
+
+        let great = &report.results[0];
+        assert_eq!(great.id, ConversationId(98765));
+        assert_eq!(great.rating_id, RatingScore::Great);
+        assert_eq!(great.rating_comments, Some("Quick and helpful".to_string()));
+        assert_eq!(great.rating_user_id, UserId(2));
+
+        assert_eq!(report.results[1].rating_id, RatingScore::NotGood);
+        assert_eq!(report.results[2].rating_id, RatingScore::Unknown(7));
+        assert_eq!(report.results[2].rating_comments, None);
+    }
+
+    #[test]
+    fn parses_overall_report() {
+        let report: HappinessReport = serde_json::from_value(json!({
+            "filterTags": [],
+            "current": {
+                "startDate": "2018-01-01T00:00:00Z",
+                "endDate": "2018-01-31T00:00:00Z",
+                "greatCount": 33,
+                "okayCount": 4,
+                "notGoodCount": 2,
+                "totalCustomers": 1220,
+                "totalCustomersWithRatings": 39,
+                "ratingsPercent": 3.19,
+                "greatPercent": 84.61,
+                "okayPercent": 10.25,
+                "notGoodPercent": 5.12,
+                "happinessScore": 79.48
+            }
+        })).expect("overall report to parse");
+
+        assert_eq!(report.current.great_count, 33);
+        assert!(report.previous.is_none());
+        assert!(report.deltas.is_none());
+    }
+}
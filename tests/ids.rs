@@ -0,0 +1,24 @@
+extern crate helpscout;
+
+extern crate serde_json;
+
+#[cfg(test)]
+mod ids {
+    use serde_json;
+
+    use super::helpscout::{ConversationId, MailboxId};
+    use super::helpscout::api::mailboxes::MailboxRef;
+
+    #[test]
+    fn ids_serialize_as_bare_numbers() {
+        let mailbox = MailboxRef { id: MailboxId(12), name: "Support".into() };
+        assert_eq!(serde_json::to_string(&mailbox).unwrap(), r#"{"id":12,"name":"Support"}"#);
+    }
+
+    #[test]
+    fn ids_wider_than_i32() {
+        let id: ConversationId = serde_json::from_str("4294967296").expect("id to parse");
+        assert_eq!(id, ConversationId(4294967296));
+        assert_eq!(id.to_string(), "4294967296");
+    }
+}
@@ -0,0 +1,300 @@
+extern crate helpscout;
+
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod mail {
+    use std::collections::HashMap;
+
+    use serde_json::{self, Value};
+
+    use super::helpscout::{AttachmentId, CustomerId, MailboxId, ThreadId, UserId};
+    use super::helpscout::api::conversations::{Conversation, ConversationThreadType};
+    use super::helpscout::api::mailboxes::MailboxRef;
+    use super::helpscout::mail::{Message, MessageAttachment, split_mbox};
+    use super::helpscout::mail::export::conversation_messages;
+    use super::helpscout::mail::import::{Directory, SkipReason, conversation_from_messages, conversation_key, customer_addresses, group_conversations};
+
+    fn conversation_json() -> Value {
+        let mut page: Value = serde_json::from_str(include_str!("fixtures/conversations.json")).unwrap();
+        let mut conversation = page["items"][0].take();
+        conversation["threads"] = json!([
+            {
+                "id": 88171991,
+                "type": "message",
+                "status": "active",
+                "createdAt": "2012-07-24T20:18:33Z",
+                "createdBy": {"id": 1234, "firstName": "Jack", "lastName": "Sprout", "email": "jack.sprout@gmail.com", "type": "user"},
+                "state": "published",
+                "body": "<p>We're on it</p>",
+                "cc": ["boss@mywork.com"],
+                "createdByCustomer": false,
+                "attachments": [{
+                    "id": 12391,
+                    "hash": "abc",
+                    "mimeType": "text/plain",
+                    "fileName": "steps.txt",
+                    "size": 5,
+                    "width": 0,
+                    "height": 0,
+                    "url": "https://secure.helpscout.net/some-url/steps.txt"
+                }]
+            },
+            {
+                "id": 88171992,
+                "type": "lineitem",
+                "status": "closed",
+                "createdAt": "2012-07-24T20:19:00Z",
+                "createdByCustomer": false
+            },
+            {
+                "id": 88171990,
+                "type": "customer",
+                "status": "active",
+                "createdAt": "2012-07-23T12:34:12Z",
+                "createdBy": {"id": 29418, "firstName": "Vernon", "lastName": "Bear", "email": "vbear@mywork.com", "type": "customer"},
+                "body": "I need help!",
+                "createdByCustomer": true
+            }
+        ]);
+        conversation
+    }
+
+    fn messages(attachments: &HashMap<AttachmentId, String>) -> Vec<(ThreadId, Message)> {
+        let conversation: Conversation = serde_json::from_value(conversation_json()).unwrap();
+        conversation_messages(&conversation, "support@example.com", "example.com", attachments)
+    }
+
+    #[test]
+    fn threads_replies_by_message_id() {
+        let messages = messages(&HashMap::new());
+        assert_eq!(messages.len(), 2, "line items are left out");
+
+        let (id, ref question) = messages[0];
+        assert_eq!(id, ThreadId(88171990));
+        assert_eq!(question.header("From"), Some("\"Vernon Bear\" <vbear@mywork.com>"));
+        assert_eq!(question.header("To"), Some("support@example.com"));
+        assert_eq!(question.header("Subject"), Some("I need help!"));
+        assert_eq!(question.header("Date"), Some("Mon, 23 Jul 2012 12:34:12 +0000"));
+        assert_eq!(question.header("Message-ID"), Some("<thread-88171990.conversation-2391938111@example.com>"));
+        assert_eq!(question.header("In-Reply-To"), Some("<conversation-2391938111@example.com>"));
+        assert_eq!(question.header("Cc"), None);
+
+        let (_, ref reply) = messages[1];
+        assert_eq!(reply.header("From"), Some("\"Jack Sprout\" <jack.sprout@gmail.com>"));
+        assert_eq!(reply.header("To"), Some("\"Vernon Bear\" <vbear@mywork.com>"));
+        assert_eq!(reply.header("Cc"), Some("boss@mywork.com"));
+        assert_eq!(reply.header("Subject"), Some("Re: I need help!"));
+        assert_eq!(reply.header("In-Reply-To"), Some("<thread-88171990.conversation-2391938111@example.com>"));
+        assert_eq!(
+            reply.header("References"),
+            Some("<conversation-2391938111@example.com> <thread-88171990.conversation-2391938111@example.com>")
+        );
+        assert_eq!(reply.header("X-HelpScout-Thread-Type"), Some("message"));
+        assert!(reply.attachments.is_empty(), "attachments without data are left out");
+
+        let eml = reply.to_rfc5322();
+        assert!(eml.contains("Content-Type: text/html; charset=utf-8\r\n"));
+        assert!(eml.contains("PHA+V2UncmUgb24gaXQ8L3A+\r\n"));
+    }
+
+    #[test]
+    fn attaches_attachment_data() {
+        let mut data = HashMap::new();
+        data.insert(AttachmentId(12391), "aGVsbG8=".to_string());
+        let messages = messages(&data);
+
+        let (_, ref reply) = messages[1];
+        assert_eq!(reply.attachments, vec![MessageAttachment {
+            file_name: "steps.txt".into(),
+            mime_type: "text/plain".into(),
+            data: "aGVsbG8=".into(),
+        }]);
+
+        let eml = reply.to_rfc5322();
+        let boundary = eml.split("boundary=\"").nth(1).unwrap().split('"').next().unwrap().to_string();
+        assert_eq!(eml.matches(&format!("--{}\r\n", boundary)).count(), 2);
+        assert!(eml.ends_with(&format!("--{}--\r\n", boundary)));
+        assert!(eml.contains("Content-Disposition: attachment; filename=\"steps.txt\"\r\n"));
+        assert!(eml.contains("\r\naGVsbG8=\r\n"));
+        assert_eq!(eml, reply.to_rfc5322(), "boundaries are stable");
+    }
+
+    #[test]
+    fn encodes_non_ascii_headers() {
+        let mut message = Message::default();
+        message.push_header("From", "\"Zoë Ångström\" <zoe@example.com>");
+        message.push_header("Subject", "Café");
+        message.push_header("Empty", "");
+
+        assert_eq!(message.header("empty"), None);
+        let eml = message.to_rfc5322();
+        assert!(eml.contains("From: =?utf-8?B?Wm/DqyDDhW5nc3Ryw7Zt?= <zoe@example.com>\r\n"));
+        assert!(eml.contains("Subject: =?utf-8?B?Q2Fmw6k=?=\r\n"));
+        assert!(eml.is_ascii());
+
+        message.headers.clear();
+        message.push_header("Subject", "é".repeat(40));
+        let eml = message.to_rfc5322();
+        let subject = eml.lines().take_while(|l| !l.starts_with("MIME-Version")).collect::<Vec<_>>();
+        assert!(subject.len() > 1, "long subjects are folded");
+        assert!(subject.iter().all(|l| l.trim_end().len() <= 78));
+    }
+
+    #[test]
+    fn escapes_from_lines_in_mbox() {
+        let mut message = Message::default();
+        message.push_header("From", "\"Vernon Bear\" <vbear@mywork.com>");
+        message.push_header("Date", "Mon, 23 Jul 2012 12:34:12 +0000");
+        message.attachments.push(MessageAttachment {
+            file_name: "note.txt".into(),
+            mime_type: "text/plain".into(),
+            data: "aGVsbG8=".into(),
+        });
+
+        let mut mbox = Vec::new();
+        message.write_mbox(&mut mbox).unwrap();
+        message.write_mbox(&mut mbox).unwrap();
+        let mbox = String::from_utf8(mbox).unwrap();
+
+        assert!(mbox.starts_with("From vbear@mywork.com Mon Jul 23 12:34:12 2012\n"));
+        assert_eq!(mbox.matches("\nFrom vbear@mywork.com ").count(), 1);
+        assert_eq!(mbox.matches(">From: ").count(), 0, "only `From ` lines are escaped");
+        assert!(!mbox.contains('\r'));
+
+        // Bodies are base64, so a `From ` line can only come from a header
+        let mut odd = Message::default();
+        odd.push_header("X-Note", "a\nFrom me");
+        let mut out = Vec::new();
+        odd.write_mbox(&mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("\n>From me\n"));
+    }
+
+    fn mbox() -> Vec<Message> {
+        split_mbox(include_bytes!("fixtures/import.mbox"))
+            .iter()
+            .map(|raw| Message::parse(raw).unwrap())
+            .collect()
+    }
+
+    fn directory() -> Directory {
+        let mut directory = Directory::default();
+        directory.mailbox_email = "support@example.com".into();
+        directory.users.insert("jack.sprout@example.com".into(), UserId(1234));
+        directory.customers.insert("vbear@mywork.com".into(), CustomerId(29418));
+        directory
+    }
+
+    #[test]
+    fn parses_mbox_messages() {
+        let messages = mbox();
+        assert_eq!(messages.len(), 4);
+
+        let question = &messages[0];
+        assert_eq!(question.header("Subject"), Some("Café order missing"));
+        assert_eq!(question.addresses("From"), vec![("Vernon Bear".to_string(), "vbear@mywork.com".to_string())]);
+        assert_eq!(question.date().map(|d| d.to_rfc3339()), Some("2012-07-23T12:34:12+00:00".to_string()));
+        assert_eq!(question.message_id(), Some("<first@mywork.com>".to_string()));
+        assert!(!question.html);
+        assert!(question.body.contains("\nFrom what I can tell it shipped — can you check?"), "unescapes mboxrd and decodes quoted-printable");
+
+        let reply = &messages[1];
+        assert!(reply.html);
+        assert_eq!(reply.body.trim(), "<p>We're on it. Café orders ship Tuesdays.</p>");
+        assert_eq!(reply.references(), vec!["<first@mywork.com>".to_string()]);
+        assert_eq!(reply.attachments, vec![MessageAttachment {
+            file_name: "réceipt.pdf".into(),
+            mime_type: "application/pdf".into(),
+            data: "aGVsbG8=".into(),
+        }]);
+    }
+
+    #[test]
+    fn reads_back_exported_messages() {
+        let mut data = HashMap::new();
+        data.insert(AttachmentId(12391), "aGVsbG8=".to_string());
+
+        let mut mbox = Vec::new();
+        for (_, message) in messages(&data) {
+            message.write_mbox(&mut mbox).unwrap();
+        }
+        let read: Vec<Message> = split_mbox(&mbox).iter().map(|raw| Message::parse(raw).unwrap()).collect();
+        let written: Vec<Message> = messages(&data).into_iter().map(|(_, m)| m).collect();
+        assert_eq!(read, written);
+
+        let groups = group_conversations(read);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(conversation_key(&groups[0]), "<thread-88171990.conversation-2391938111@example.com>");
+    }
+
+    #[test]
+    fn groups_by_references() {
+        let groups = group_conversations(mbox());
+        let subjects: Vec<Vec<&str>> = groups.iter()
+            .map(|g| g.iter().map(|m| m.header("Subject").unwrap()).collect())
+            .collect();
+        assert_eq!(subjects, vec![
+            vec!["Unrelated"],
+            vec!["Café order missing", "Re: Café order missing", "Re: Café order missing"],
+        ]);
+        assert_eq!(conversation_key(&groups[1]), "<first@mywork.com>");
+    }
+
+    #[test]
+    fn maps_senders_to_users_and_customers() {
+        let groups = group_conversations(mbox());
+        let directory = directory();
+
+        assert_eq!(customer_addresses(&groups[0], &directory), vec![("".to_string(), "someone@elsewhere.com".to_string())]);
+        assert_eq!(customer_addresses(&groups[1], &directory), vec![("Vernon Bear".to_string(), "vbear@mywork.com".to_string())]);
+
+        let mailbox = MailboxRef { id: MailboxId(1234), name: "Support".into() };
+        let conversation = conversation_from_messages(&groups[1], mailbox, &directory).unwrap();
+        assert_eq!(conversation.customer.id, 29418);
+        assert_eq!(conversation.subject, "Café order missing");
+        assert_eq!(conversation.created_at.map(|d| d.to_rfc3339()), Some("2012-07-23T12:34:12+00:00".to_string()));
+        assert!(conversation.validate().is_ok());
+
+        let threads = &conversation.threads;
+        assert_eq!(threads.len(), 3);
+        assert_eq!(threads[0].conversation_thread_type, ConversationThreadType::Customer);
+        assert_eq!(threads[0].created_by.id, 29418);
+        assert!(threads[0].body.contains("arrived.<br>\nFrom what"));
+        assert_eq!(threads[1].conversation_thread_type, ConversationThreadType::Message);
+        assert_eq!(threads[1].created_by.id, 1234);
+        assert_eq!(threads[1].cc, Some(vec!["boss@mywork.com".to_string()]));
+        assert_eq!(threads[1].created_at.map(|d| d.to_rfc3339()), Some("2012-07-24T20:18:33+00:00".to_string()));
+        assert_eq!(threads[2].conversation_thread_type, ConversationThreadType::Customer);
+
+        // Customers have to be matched or created first
+        let mailbox = MailboxRef { id: MailboxId(1234), name: "Support".into() };
+        let unknown = conversation_from_messages(&groups[0], mailbox, &directory);
+        assert_eq!(unknown.err(), Some(SkipReason::UnknownCustomer("someone@elsewhere.com".into())));
+    }
+
+    #[test]
+    fn replies_from_the_mailbox_need_a_fallback_user() {
+        let raw = b"From: Support <support@example.com>\r\nTo: someone@elsewhere.com\r\nSubject: Welcome\r\nDate: Sun, 22 Jul 2012 09:00:00 +0000\r\nMessage-ID: <welcome@example.com>\r\n\r\nThanks for signing up\r\n";
+        let messages = vec![Message::parse(raw).unwrap()];
+        let mailbox = || MailboxRef { id: MailboxId(1234), name: "Support".into() };
+
+        let mut directory = directory();
+        directory.customers.insert("someone@elsewhere.com".into(), CustomerId(31337));
+        let skipped = conversation_from_messages(&messages, mailbox(), &directory);
+        assert_eq!(skipped.err(), Some(SkipReason::NoUser("support@example.com".into())));
+
+        directory.fallback_user = Some(UserId(4321));
+        let conversation = conversation_from_messages(&messages, mailbox(), &directory).unwrap();
+        assert_eq!(conversation.customer.id, 31337);
+        assert_eq!(conversation.threads[0].conversation_thread_type, ConversationThreadType::Message);
+        assert_eq!(conversation.threads[0].created_by.id, 4321);
+    }
+
+    #[test]
+    fn decodes_windows_1252() {
+        let raw = b"From: someone@elsewhere.com\r\nSubject: Price\r\nContent-Type: text/plain; charset=windows-1252\r\nContent-Transfer-Encoding: quoted-printable\r\n\r\n=93It=92s =8050=94 =96 caf=E9\r\n";
+        let message = Message::parse(raw).unwrap();
+        assert_eq!(message.body.trim(), "\u{201C}It\u{2019}s \u{20AC}50\u{201D} \u{2013} caf\u{E9}");
+    }
+}
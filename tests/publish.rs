@@ -0,0 +1,221 @@
+#![cfg(feature = "publish")]
+extern crate helpscout;
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod publish {
+    use std::cell::RefCell;
+    use std::path::Path;
+
+    use serde_json::{self, Value};
+
+    use super::helpscout::{Collection, HelpScoutError};
+    use super::helpscout::docs::articles::{Article, ArticleStatus, NewArticle};
+    use super::helpscout::docs::categories::Category;
+    use super::helpscout::docs::collections::DocsCollection;
+    use super::helpscout::docs::publish::{self, Action, MarkdownArticle, Remote};
+
+    const SITE_ID: &str = "52404efc3e3e9bd67a3dc68a";
+
+    // A site with the fixture collections, categories and articles that
+    // records what gets written to it.
+    #[derive(Default)]
+    struct FixtureSite {
+        writes: RefCell<Vec<(Option<String>, Value)>>,
+    }
+
+    impl FixtureSite {
+        fn article_values() -> Vec<Value> {
+            serde_json::from_str(include_str!("fixtures/docs_collection_articles.json")).unwrap()
+        }
+
+        fn saved(&self, id: Option<&str>, article: &NewArticle) -> Result<Article, HelpScoutError> {
+            let body = serde_json::to_value(article)?;
+            self.writes.borrow_mut().push((id.map(|id| id.to_string()), body.clone()));
+
+            let mut saved = FixtureSite::article_values().remove(0);
+            for (key, value) in body.as_object().unwrap() {
+                saved[key] = value.clone();
+            }
+            saved["id"] = json!(id.unwrap_or("5214c77c45667acd25394b99"));
+            Ok(serde_json::from_value(saved)?)
+        }
+    }
+
+    impl Remote for FixtureSite {
+        fn collections(&self, site_id: &str) -> Result<Vec<DocsCollection>, HelpScoutError> {
+            let res: Value = serde_json::from_str(include_str!("fixtures/docs_collections.json"))?;
+            let collections: Collection<DocsCollection> = serde_json::from_value(res["collections"].clone())?;
+            Ok(collections.items.into_iter().filter(|c| c.site_id == site_id).collect())
+        }
+
+        fn categories(&self, collection_id: &str) -> Result<Vec<Category>, HelpScoutError> {
+            let res: Value = serde_json::from_str(include_str!("fixtures/docs_categories.json"))?;
+            let categories: Collection<Category> = serde_json::from_value(res["categories"].clone())?;
+            Ok(categories.items.into_iter().filter(|c| c.collection_id == collection_id).collect())
+        }
+
+        fn articles(&self, collection_id: &str) -> Result<Vec<Article>, HelpScoutError> {
+            let articles: Vec<Article> = serde_json::from_value(Value::Array(FixtureSite::article_values()))?;
+            Ok(articles.into_iter().filter(|a| a.collection_id == collection_id).collect())
+        }
+
+        fn create(&self, article: &NewArticle) -> Result<Article, HelpScoutError> {
+            self.saved(None, article)
+        }
+
+        fn update(&self, id: &str, article: &NewArticle) -> Result<Article, HelpScoutError> {
+            self.saved(Some(id), article)
+        }
+    }
+
+    fn markdown(path: &str, source: &str) -> MarkdownArticle {
+        MarkdownArticle::parse(Path::new(path), source).expect("article to parse")
+    }
+
+    // One file matching the site, one changed and one new.
+    fn local_articles() -> Vec<MarkdownArticle> {
+        vec![
+            markdown("docs/getting-started.md", "---\ntitle: Getting Started\ncollection: Product Guides\ncategory: basics\nkeywords: [setup]\n---\n# Welcome\n\nHello *there*.\n"),
+            markdown("docs/billing.md", "---\ntitle: Billing\ncollection: product-guides\ncategory: Billing\n---\nInvoices go out **yearly**.\n"),
+            markdown("docs/new-feature.md", "---\ntitle: New Feature\ncollection: 5214c77d45667acd25394b52\n---\nIt's here.\n"),
+        ]
+    }
+
+    #[test]
+    fn parses_front_matter_and_renders_html() {
+        let source = "---\ntitle: Getting Started\ncollection: Guides\ncategory: Basics\nkeywords: [setup, install]\nstatus: notpublished\n---\n# Welcome\n\nHello *there*.\n";
+        let article = MarkdownArticle::parse(Path::new("docs/getting-started.md"), source).expect("article to parse");
+
+        assert_eq!(article.slug, "getting-started");
+        assert_eq!(article.front_matter.title, "Getting Started");
+        assert_eq!(article.front_matter.category, Some("Basics".into()));
+        assert_eq!(article.front_matter.keywords, vec!["setup".to_string(), "install".to_string()]);
+        assert_eq!(article.front_matter.status, Some(ArticleStatus::NotPublished));
+        assert_eq!(article.html, "<h1>Welcome</h1>\n<p>Hello <em>there</em>.</p>\n");
+    }
+
+    #[test]
+    fn explicit_slug_wins_over_file_name() {
+        let source = "---\ntitle: Billing\ncollection: Guides\nslug: billing-faq\n---\nBody\n";
+        let article = MarkdownArticle::parse(Path::new("billing.md"), source).expect("article to parse");
+        assert_eq!(article.slug, "billing-faq");
+    }
+
+    #[test]
+    fn missing_front_matter_is_an_error() {
+        match MarkdownArticle::parse(Path::new("notes.md"), "# Just markdown\n") {
+            Err(HelpScoutError::InputParseError(ref e)) => assert!(e.contains("notes.md")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn plans_each_kind_of_action() {
+        let plan = publish::plan(&FixtureSite::default(), SITE_ID, local_articles()).expect("plan to build");
+
+        assert_eq!(plan.actions.len(), 4);
+        match plan.actions[0] {
+            Action::Unchanged { ref id, ref slug } => {
+                assert_eq!(id, "5214c77c45667acd25394b51");
+                assert_eq!(slug, "getting-started");
+            },
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match plan.actions[1] {
+            Action::Update { ref id, ref article, ref changes, .. } => {
+                assert_eq!(id, "5214c77c45667acd25394b55");
+                assert_eq!(article.categories, Some(vec!["5214c77d45667acd25394b54".to_string()]));
+                assert_eq!(*changes, vec!["text", "category"]);
+            },
+            ref other => panic!("unexpected {:?}", other),
+        }
+        match plan.actions[2] {
+            Action::Create { ref article, ref source } => {
+                assert_eq!(article.slug, Some("new-feature".into()));
+                assert_eq!(article.collection_id, "5214c77d45667acd25394b52");
+                assert_eq!(article.status, Some(ArticleStatus::Published));
+                assert_eq!(source, Path::new("docs/new-feature.md"));
+            },
+            ref other => panic!("unexpected {:?}", other),
+        }
+        assert!(!plan.is_empty());
+        assert!(plan.to_string().ends_with("1 to create, 1 to update, 1 to unpublish, 1 unchanged"));
+    }
+
+    #[test]
+    fn unpublishes_published_orphans_only() {
+        let plan = publish::plan(&FixtureSite::default(), SITE_ID, local_articles()).expect("plan to build");
+
+        let unpublished: Vec<&str> = plan.actions.iter().filter_map(|a| match *a {
+            Action::Unpublish { ref id, .. } => Some(id.as_str()),
+            _ => None,
+        }).collect();
+        // draft-notes has no file either, but isn't published
+        assert_eq!(unpublished, vec!["5214c77c45667acd25394b56"]);
+
+        match plan.actions[3] {
+            Action::Unpublish { ref article, .. } => {
+                assert_eq!(article.slug, Some("old-pricing".into()));
+                assert_eq!(article.status, Some(ArticleStatus::NotPublished));
+                assert_eq!(article.categories, Some(vec!["5214c77d45667acd25394b54".to_string()]));
+            },
+            ref other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn lists_every_changed_field() {
+        let local = markdown("getting-started.md", "---\ntitle: Welcome\ncollection: Product Guides\nkeywords: [setup, install]\nstatus: notpublished\n---\n# Welcome\n\nHello *there*.\n");
+        let plan = publish::plan(&FixtureSite::default(), SITE_ID, vec![local]).expect("plan to build");
+
+        match plan.actions[0] {
+            Action::Update { ref changes, .. } => assert_eq!(*changes, vec!["title", "status", "keywords"]),
+            ref other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unknown_collection_is_an_error() {
+        let local = markdown("docs/faq.md", "---\ntitle: FAQ\ncollection: Nowhere\n---\nBody\n");
+        match publish::plan(&FixtureSite::default(), SITE_ID, vec![local]) {
+            Err(HelpScoutError::InputParseError(ref e)) => assert!(e.contains("docs/faq.md") && e.contains("Nowhere")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn duplicate_slug_in_a_collection_is_an_error() {
+        let mut local = local_articles();
+        local.push(markdown("guides/billing.md", "---\ntitle: Billing Again\ncollection: Product Guides\n---\nBody\n"));
+        match publish::plan(&FixtureSite::default(), SITE_ID, local) {
+            Err(HelpScoutError::InputParseError(ref e)) => assert!(e.contains("guides/billing.md") && e.contains("docs/billing.md")),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn apply_writes_everything_but_unchanged_articles() {
+        let site = FixtureSite::default();
+        let plan = publish::plan(&site, SITE_ID, local_articles()).expect("plan to build");
+        let written = plan.apply(&site).expect("plan to apply");
+
+        let names: Vec<&str> = written.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["Billing", "New Feature", "Old Pricing"]);
+
+        let writes = site.writes.borrow();
+        let ids: Vec<Option<&str>> = writes.iter().map(|&(ref id, _)| id.as_ref().map(|id| id.as_str())).collect();
+        assert_eq!(ids, vec![Some("5214c77c45667acd25394b55"), None, Some("5214c77c45667acd25394b56")]);
+
+        // Unpublishing leaves related articles and keywords as they are.
+        assert_eq!(writes[2].1, json!({
+            "collectionId": "5214c77d45667acd25394b52",
+            "name": "Old Pricing",
+            "text": "<p>Plans start at $10.</p>",
+            "status": "notpublished",
+            "slug": "old-pricing",
+            "categories": ["5214c77d45667acd25394b54"]
+        }));
+    }
+}
@@ -0,0 +1,408 @@
+extern crate helpscout;
+#[macro_use]
+extern crate serde_json;
+extern crate serde_url_params;
+extern crate chrono;
+extern crate chrono_tz;
+
+#[cfg(test)]
+mod reports {
+    use std::time::Duration;
+
+    use chrono::prelude::*;
+    use chrono_tz::America::Chicago;
+    use serde_json;
+    use serde_url_params;
+
+    use super::helpscout::{ConversationId, MailboxId, FolderId, UserId};
+    use super::helpscout::api::report;
+    use super::helpscout::api::reports::{ReportBuilder, ReportFilters, SortOrder, TypeFilter};
+    use super::helpscout::api::reports::happiness::RatingSortField;
+    use super::helpscout::api::reports::user::ConversationHistorySortField;
+    use super::helpscout::api::reports::chat::ChatReport;
+    use super::helpscout::api::reports::email::EmailReport;
+    use super::helpscout::api::reports::phone::PhoneReport;
+    use super::helpscout::api::reports::company::overall::CompanyReport;
+    use super::helpscout::api::reports::company::customers_helped::CustomersHelpedReport;
+    use super::helpscout::api::reports::conversations::{ConvReportViewByType, DrillDownField, FieldStatistics, ConversationStatus, ConversationType};
+    use super::helpscout::api::reports::conversations::drill_down::DrillDownConversationsReport;
+    use super::helpscout::api::reports::conversations::volume_by_channel::NewConversationsByChannelReport;
+    use super::helpscout::api::reports::productivity::{TimeSeriesReport, TimeStatistics};
+    use super::helpscout::api::reports::productivity::replies_sent::RepliesSentReport;
+    use super::helpscout::api::reports::productivity::resolved::ResolvedReport;
+    use super::helpscout::api::reports::user::conversation_history::ConversationHistoryReport;
+    use super::helpscout::api::reports::user::happiness::UserHappinessReport;
+
+    #[test]
+    fn parses_custom_fields_report() {
+        let report: FieldStatistics = serde_json::from_str(include_str!("fixtures/report_fields.json")).expect("fields report to parse");
+
+        assert_eq!(report.count, 1);
+        let field = &report.fields[0];
+        assert_eq!(field.name, "Plan");
+        assert_eq!(field.mailbox_id, MailboxId(5678));
+        assert_eq!(field.values.len(), 2);
+        assert_eq!(field.values[0].name, Some("Free".to_string()));
+        assert_eq!(field.summary.total_answered, 20);
+    }
+
+    #[test]
+    fn parses_fields_drill_down_report() {
+        let report: DrillDownConversationsReport = serde_json::from_str(include_str!("fixtures/report_fields_drilldown.json")).expect("fields drilldown to parse");
+
+        let conversation = &report.conversations.results[0];
+        assert_eq!(conversation.id, ConversationId(2391938111));
+        assert_eq!(conversation.status, ConversationStatus::Closed);
+        assert_eq!(conversation.tags[0].name, "billing");
+    }
+
+    #[test]
+    fn parses_new_conversations_by_channel_report() {
+        let report: NewConversationsByChannelReport = serde_json::from_str(include_str!("fixtures/report_volume_by_channel.json")).expect("volume by channel to parse");
+
+        assert_eq!(report.current.len(), 2);
+        assert_eq!(report.current[0].total(), 16);
+        assert_eq!(report.current[1].phone, 0);
+        assert!(report.previous.is_none());
+    }
+
+    #[test]
+    fn parses_company_report() {
+        let report: CompanyReport = serde_json::from_str(include_str!("fixtures/report_company.json")).expect("company report to parse");
+
+        assert_eq!(report.filter_tags.as_ref().unwrap()[0].name, "billing");
+        assert_eq!(report.current.customers_helped, Some(412));
+        assert_eq!(report.current.handle_time, Some(Duration::from_millis(312500)));
+        assert_eq!(report.previous.as_ref().unwrap().handle_time, Some(Duration::from_secs(0)));
+        assert_eq!(report.deltas.as_ref().unwrap().handle_time, -4.1);
+
+        let users = report.users.as_ref().unwrap();
+        assert_eq!(users[0].user, UserId(1234));
+        assert_eq!(users[0].previous_replies, Some(198));
+        assert_eq!(users[1].happiness, None);
+    }
+
+    #[test]
+    fn parses_company_customers_helped_report() {
+        let report: CustomersHelpedReport = serde_json::from_str(include_str!("fixtures/report_company_customers_helped.json")).expect("customers helped to parse");
+
+        assert_eq!(report.current.len(), 2);
+        assert_eq!(report.current[1].customers, 22);
+        assert_eq!(report.previous.as_ref().unwrap()[0].date, utc("2017-12-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn parses_email_report() {
+        let report: EmailReport = serde_json::from_str(include_str!("fixtures/report_email.json")).expect("email report to parse");
+
+        let current = &report.current;
+        assert_eq!(current.volume.messages_received, Some(611));
+        assert_eq!(current.response_times.first_response_time, Some(Duration::from_secs(5400)));
+        assert_eq!(current.response_times.response_time, Some(Duration::from_millis(7210500)));
+        assert_eq!(current.resolution.resolution_time, Some(Duration::from_secs(86400)));
+        assert_eq!(current.replies_sent, Some(702));
+        assert_eq!(current.busiest_times.len(), 2);
+        assert_eq!((current.busiest_times[0].day, current.busiest_times[0].hour), (1, 10));
+
+        let previous = report.previous.as_ref().unwrap();
+        assert_eq!(previous.response_times.first_response_time, None);
+        assert_eq!(previous.response_times.response_time, Some(Duration::from_secs(6900)));
+        assert!(previous.busiest_times.is_empty());
+    }
+
+    #[test]
+    fn parses_chat_report() {
+        let report: ChatReport = serde_json::from_str(include_str!("fixtures/report_chat.json")).expect("chat report to parse");
+
+        assert!(report.filter_tags.is_none());
+        assert_eq!(report.current.wait_time, Some(Duration::from_millis(38500)));
+        assert_eq!(report.current.chat_duration, Some(Duration::from_secs(612)));
+        assert_eq!(report.current.missed_chats, Some(4));
+        assert_eq!(report.current.busiest_times[0].count, 17);
+        assert!(report.previous.is_none());
+    }
+
+    #[test]
+    fn parses_phone_report() {
+        let report: PhoneReport = serde_json::from_str(include_str!("fixtures/report_phone.json")).expect("phone report to parse");
+
+        assert_eq!(report.filter_tags.as_ref().unwrap()[0].name, "sales");
+        assert_eq!(report.current.volume.messages_received, None);
+        assert_eq!(report.current.response_times.first_response_time, None);
+        assert_eq!(report.current.resolution.resolved_on_first_reply, Some(20));
+        assert_eq!((report.current.inbound_calls, report.current.outbound_calls), (Some(27), Some(8)));
+    }
+
+    #[test]
+    fn parses_time_series_report() {
+        let report: TimeSeriesReport = serde_json::from_str(include_str!("fixtures/report_productivity_response_time.json")).expect("time series to parse");
+
+        assert_eq!(report.current.len(), 3);
+        assert_eq!(report.current[0].date, utc("2018-01-01T00:00:00Z"));
+        assert_eq!(report.current[0].time, Some(Duration::from_secs(5400)));
+        assert_eq!(report.current[1].time, Some(Duration::from_millis(4820250)));
+        assert_eq!(report.current[2].time, None);
+
+        let previous = report.previous.as_ref().unwrap();
+        assert_eq!(previous[0].time, Some(Duration::from_secs(6100)));
+        assert_eq!(previous[1].date, utc("2017-12-11T00:00:00Z"));
+        assert_eq!(previous[1].time, None);
+    }
+
+    #[test]
+    fn productivity_params_are_camel_case() {
+        let start = utc("2018-01-01T00:00:00Z");
+        let end = utc("2018-02-01T00:00:00Z");
+        let builder = report(start, end).previous_period().set_office_hours(true).productivity()
+            .set_view_by(ConvReportViewByType::Week);
+
+        let params = serde_url_params::to_string(&builder).expect("params to serialize");
+        assert!(params.contains("previousStart=2017-12-01T00%3A00%3A00Z"), "{}", params);
+        assert!(params.contains("previousEnd=2018-01-01T00%3A00%3A00Z"), "{}", params);
+        assert!(params.contains("officeHours=1"), "{}", params);
+        assert!(params.contains("viewBy=week"), "{}", params);
+        assert!(!params.contains('_'), "{}", params);
+    }
+
+    #[test]
+    fn parses_user_conversation_history_report() {
+        let report: ConversationHistoryReport = serde_json::from_str(include_str!("fixtures/report_user_conversation_history.json")).expect("conversation history to parse");
+
+        let conversations = &report.conversations;
+        assert_eq!((conversations.page, conversations.pages, conversations.count), (1, 3, 52));
+        assert_eq!(conversations.results[0].id, ConversationId(2391938111));
+        assert_eq!(conversations.results[0].status, ConversationStatus::Closed);
+        assert_eq!(conversations.results[0].resolve_time, Some(Duration::from_millis(7260500)));
+        assert_eq!(conversations.results[1].customer_email, None);
+        assert_eq!(conversations.results[1].response_time, Some(Duration::from_secs(540)));
+        assert_eq!(conversations.results[1].resolve_time, None);
+    }
+
+    #[test]
+    fn parses_user_customers_helped_report() {
+        let report: CustomersHelpedReport = serde_json::from_str(include_str!("fixtures/report_user_customers_helped.json")).expect("customers helped to parse");
+
+        assert_eq!(report.current.iter().map(|s| s.customers).sum::<i32>(), 15);
+        assert!(report.previous.is_none());
+    }
+
+    #[test]
+    fn parses_user_drill_down_report() {
+        let report: DrillDownConversationsReport = serde_json::from_str(include_str!("fixtures/report_user_drilldown.json")).expect("user drilldown to parse");
+
+        assert_eq!(report.conversations.page, 2);
+        let conversation = &report.conversations.results[0];
+        assert_eq!(conversation.status, ConversationStatus::Pending);
+        assert_eq!(conversation.conversation_type, ConversationType::Chat);
+        assert!(conversation.tags.is_empty());
+    }
+
+    #[test]
+    fn parses_user_happiness_report() {
+        let report: UserHappinessReport = serde_json::from_str(include_str!("fixtures/report_user_happiness.json")).expect("user happiness to parse");
+
+        assert_eq!(report.current.total_customer_ratings, Some(40));
+        assert_eq!(report.current.great_count, Some(34));
+        assert_eq!(report.current.happiness_score, Some(80.0));
+
+        let previous = report.previous.as_ref().unwrap();
+        assert_eq!(previous.total_customer_ratings, Some(0));
+        assert_eq!(previous.happiness_score, None);
+        assert_eq!(report.deltas.as_ref().unwrap().great_count, 100.0);
+    }
+
+    #[test]
+    fn parses_user_replies_report() {
+        let report: RepliesSentReport = serde_json::from_str(include_str!("fixtures/report_user_replies.json")).expect("user replies to parse");
+
+        assert_eq!(report.current[0].replies, 31);
+        assert_eq!(report.current[1].replies, 0);
+        assert_eq!(report.previous.as_ref().unwrap()[1].date, utc("2017-12-02T00:00:00Z"));
+    }
+
+    #[test]
+    fn parses_user_resolutions_report() {
+        let report: ResolvedReport = serde_json::from_str(include_str!("fixtures/report_user_resolutions.json")).expect("user resolutions to parse");
+
+        assert_eq!(report.current.len(), 2);
+        assert_eq!(report.current[1].resolved, 21);
+        assert!(report.previous.is_none());
+    }
+
+    #[test]
+    fn fields_drill_down_sends_field_params() {
+        let start = Utc.ymd(2018, 1, 1).and_hms(0, 0, 0);
+        let end = Utc.ymd(2018, 2, 1).and_hms(0, 0, 0);
+        let builder = report(start, end).conversations().set_field(DrillDownField::Tags, 42);
+
+        let params = serde_url_params::to_string(&builder).expect("params to serialize");
+        assert!(params.contains("field=tags"), "{}", params);
+        assert!(params.contains("fieldid=42"), "{}", params);
+    }
+
+    #[test]
+    fn typed_filters_are_comma_joined() {
+        let start = Utc.ymd(2018, 1, 1).and_hms(0, 0, 0);
+        let end = Utc.ymd(2018, 2, 1).and_hms(0, 0, 0);
+        let builder = report(start, end).productivity()
+            .mailboxes(&[MailboxId(1), MailboxId(2)])
+            .tags(&[7])
+            .types(&[ConversationType::Email, ConversationType::Phone])
+            .folders(&[])
+            .previous(start, end);
+
+        let params = serde_url_params::to_string(&builder).expect("params to serialize");
+        assert!(params.contains("mailboxes=1%2C2"), "{}", params);
+        assert!(params.contains("tags=7"), "{}", params);
+        assert!(params.contains("types=email%2Cphone"), "{}", params);
+        assert!(!params.contains("folders"), "{}", params);
+        assert!(params.contains("previousStart="), "{}", params);
+    }
+
+    #[test]
+    fn filters_are_shared_by_every_builder() {
+        let start = Utc.ymd(2018, 1, 1).and_hms(0, 0, 0);
+        let end = Utc.ymd(2018, 2, 1).and_hms(0, 0, 0);
+
+        let conversations = report(start, end).conversations().folders(&[FolderId(3)]);
+        let user = report(start, end).user().folders(&[FolderId(3)]);
+
+        assert!(serde_url_params::to_string(&conversations).unwrap().contains("folders=3"));
+        assert!(serde_url_params::to_string(&user).unwrap().contains("folders=3"));
+    }
+
+    #[test]
+    fn channel_reports_take_shared_filters() {
+        let start = utc("2018-01-01T00:00:00Z");
+        let end = utc("2018-02-01T00:00:00Z");
+        let channels = report(start, end).channels()
+            .mailboxes(&[MailboxId(1)])
+            .folders(&[FolderId(3)]);
+
+        let params = serde_url_params::to_string(&channels).expect("params to serialize");
+        assert!(params.contains("mailboxes=1"), "{}", params);
+        assert!(params.contains("folders=3"), "{}", params);
+        assert!(!params.contains("types"), "{}", params);
+    }
+
+    #[test]
+    fn user_sorts_send_their_own_fields() {
+        let start = utc("2018-01-01T00:00:00Z");
+        let end = utc("2018-02-01T00:00:00Z");
+
+        let history = report(start, end).user().set_history_sort(ConversationHistorySortField::RepliesSent, SortOrder::Desc);
+        let params = serde_url_params::to_string(&history).expect("params to serialize");
+        assert!(params.contains("sortField=repliesSent"), "{}", params);
+        assert!(params.contains("sortOrder=DESC"), "{}", params);
+
+        let ratings = report(start, end).user().set_ratings_sort(RatingSortField::ModifiedAt, SortOrder::Asc);
+        let params = serde_url_params::to_string(&ratings).expect("params to serialize");
+        assert!(params.contains("sortField=modifiedAt"), "{}", params);
+        assert!(params.contains("sortOrder=ASC"), "{}", params);
+    }
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn last_week_uses_local_midnights() {
+        let now = DateTime::parse_from_rfc3339("2018-03-14T10:00:00-05:00").unwrap();
+        let report = ReportBuilder::last_week(&now);
+
+        assert_eq!(report.start, utc("2018-03-05T05:00:00Z"));
+        assert_eq!(report.end, utc("2018-03-12T05:00:00Z"));
+        assert_eq!(report.previous_start, Some(utc("2018-02-26T05:00:00Z")));
+        assert_eq!(report.previous_end, Some(utc("2018-03-05T05:00:00Z")));
+    }
+
+    #[test]
+    fn this_month_compares_against_the_same_stretch_last_month() {
+        let now = DateTime::parse_from_rfc3339("2018-03-14T10:00:00-05:00").unwrap();
+        let report = ReportBuilder::this_month(&now);
+
+        assert_eq!(report.start, utc("2018-03-01T05:00:00Z"));
+        assert_eq!(report.end, utc("2018-03-14T15:00:00Z"));
+        assert_eq!(report.previous_start, Some(utc("2018-02-01T05:00:00Z")));
+        assert_eq!(report.previous_end, Some(utc("2018-02-14T15:00:00Z")));
+
+        // February is shorter, so the comparison stops at the end of it
+        let now = DateTime::parse_from_rfc3339("2018-03-31T23:00:00-05:00").unwrap();
+        let report = ReportBuilder::this_month(&now);
+        assert_eq!(report.previous_end, Some(utc("2018-03-01T05:00:00Z")));
+    }
+
+    #[test]
+    fn last_month_covers_the_whole_month() {
+        let now = DateTime::parse_from_rfc3339("2018-01-10T12:00:00+01:00").unwrap();
+        let report = ReportBuilder::last_month(&now);
+
+        assert_eq!(report.start, utc("2017-11-30T23:00:00Z"));
+        assert_eq!(report.end, utc("2017-12-31T23:00:00Z"));
+        assert_eq!(report.previous_start, Some(utc("2017-10-31T23:00:00Z")));
+        assert_eq!(report.previous_end, Some(utc("2017-11-30T23:00:00Z")));
+    }
+
+    #[test]
+    fn presets_follow_the_calendar_across_daylight_saving() {
+        // Chicago moved to daylight time on 2018-03-11, so last week was 167 hours
+        let now = utc("2018-03-14T15:00:00Z").with_timezone(&Chicago);
+        let report = ReportBuilder::last_week(&now);
+
+        assert_eq!(report.start, utc("2018-03-05T06:00:00Z"));
+        assert_eq!(report.end, utc("2018-03-12T05:00:00Z"));
+        assert_eq!(report.previous_start, Some(utc("2018-02-26T06:00:00Z")));
+        assert_eq!(report.previous_end, Some(utc("2018-03-05T06:00:00Z")));
+
+        let report = ReportBuilder::this_month(&now);
+        assert_eq!(report.start, utc("2018-03-01T06:00:00Z"));
+        assert_eq!(report.previous_start, Some(utc("2018-02-01T06:00:00Z")));
+        assert_eq!(report.previous_end, Some(utc("2018-02-14T16:00:00Z")));
+    }
+
+    #[test]
+    fn previous_takes_any_time_zone() {
+        let start = utc("2018-03-12T05:00:00Z");
+        let end = utc("2018-03-19T05:00:00Z");
+        let previous_start = utc("2018-03-05T06:00:00Z").with_timezone(&Chicago);
+        let previous_end = utc("2018-03-12T05:00:00Z").with_timezone(&Chicago);
+        let builder = report(start, end).user().previous(previous_start, previous_end);
+
+        let params = serde_url_params::to_string(&builder).expect("params to serialize");
+        assert!(params.contains("previousStart=2018-03-05T06%3A00%3A00Z"), "{}", params);
+        assert!(params.contains("previousEnd=2018-03-12T05%3A00%3A00Z"), "{}", params);
+    }
+
+    #[test]
+    fn previous_period_flows_into_builders() {
+        let start = utc("2018-01-10T00:00:00Z");
+        let end = utc("2018-01-20T00:00:00Z");
+        let builder = report(start, end).previous_period().set_office_hours(true).user();
+
+        let params = serde_url_params::to_string(&builder).expect("params to serialize");
+        assert!(params.contains("previousStart=2017-12-31T00%3A00%3A00Z"), "{}", params);
+        assert!(params.contains("previousEnd=2018-01-10T00%3A00%3A00Z"), "{}", params);
+        assert!(params.contains("officeHours=1"), "{}", params);
+    }
+
+    #[test]
+    fn durations_accept_every_shape_helpscout_sends() {
+        let parse = |time: serde_json::Value| -> Option<Duration> {
+            let stats: TimeStatistics = serde_json::from_value(json!({"date": "2018-01-01T00:00:00Z", "time": time})).unwrap();
+            stats.time
+        };
+
+        assert_eq!(parse(json!(90)), Some(Duration::from_secs(90)));
+        assert_eq!(parse(json!(1.5)), Some(Duration::from_millis(1500)));
+        assert_eq!(parse(json!("42")), Some(Duration::from_secs(42)));
+        assert_eq!(parse(json!(null)), None);
+        assert_eq!(parse(json!(-1)), None);
+        assert_eq!(parse(json!("")), None);
+
+        let missing: TimeStatistics = serde_json::from_value(json!({"date": "2018-01-01T00:00:00Z"})).unwrap();
+        assert_eq!(missing.time, None);
+
+        let bad = serde_json::from_value::<TimeStatistics>(json!({"date": "2018-01-01T00:00:00Z", "time": "soon"}));
+        assert!(bad.is_err());
+    }
+}
@@ -0,0 +1,121 @@
+extern crate helpscout;
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod scorecard {
+    use serde_json::{self, Value};
+
+    use super::helpscout::UserId;
+    use super::helpscout::api::reports::export::ToTable;
+    use super::helpscout::api::reports::scorecard::{Metric, Scorecard};
+    use super::helpscout::api::reports::user::overall::UserReport;
+
+    fn stats(resolved: i32, response_time: Value) -> Value {
+        json!({
+            "startDate": "2018-01-08T00:00:00Z",
+            "endDate": "2018-01-15T00:00:00Z",
+            "totalDays": 7,
+            "resolved": resolved,
+            "conversationsCreated": 0,
+            "closed": resolved,
+            "totalReplies": resolved * 2,
+            "resolvedOnFirstReply": 0,
+            "percentResolvedOnFirstReply": 0.0,
+            "repliesToResolve": 2.0,
+            "handleTime": 60,
+            "happinessScore": 90.0,
+            "responseTime": response_time,
+            "resolutionTime": 3600,
+            "repliesPerDay": 1.0,
+            "customersHelped": resolved,
+            "totalConversations": resolved,
+            "conversationsPerDay": 1.0,
+            "busiestDay": 1
+        })
+    }
+
+    fn report(id: i64, name: &str, current: Value, previous: Option<Value>) -> UserReport {
+        serde_json::from_value(json!({
+            "filterTags": [],
+            "user": {
+                "id": id,
+                "hasPhoto": false,
+                "createdAt": "2017-01-01T00:00:00Z",
+                "name": name,
+                "totalCustomersHelped": 0,
+                "photoUrl": ""
+            },
+            "current": current,
+            "previous": previous
+        })).unwrap()
+    }
+
+    fn team() -> Vec<UserReport> {
+        vec![
+            report(1, "Ann", stats(10, json!(600)), Some(stats(5, json!(1200)))),
+            report(2, "Bob", stats(30, json!(300)), None),
+            report(3, "Cat", stats(20, Value::Null), Some(stats(20, json!(300)))),
+        ]
+    }
+
+    #[test]
+    fn ranks_by_metric() {
+        let scorecard = Scorecard::from_reports(team(), Metric::Resolved);
+        let names: Vec<&str> = scorecard.rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Bob", "Cat", "Ann"]);
+        assert_eq!(scorecard.rows[0].rank, 1);
+        assert_eq!(scorecard.rows[0].user_id, UserId(2));
+
+        // Lower is better for times, and agents without one go last
+        let scorecard = Scorecard::from_reports(team(), Metric::ResponseTime);
+        let names: Vec<&str> = scorecard.rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Bob", "Ann", "Cat"]);
+    }
+
+    #[test]
+    fn scores_deltas_and_percentiles() {
+        let scorecard = Scorecard::from_reports(team(), Metric::Resolved);
+
+        let ann = scorecard.rows.iter().find(|r| r.name == "Ann").unwrap();
+        let resolved = ann.score(Metric::Resolved).unwrap();
+        assert_eq!(resolved.value, Some(10.0));
+        assert_eq!(resolved.previous, Some(5.0));
+        assert_eq!(resolved.delta, Some(100.0));
+        assert_eq!(resolved.percentile, Some(0.0));
+        assert_eq!(ann.score(Metric::ResponseTime).unwrap().delta, Some(-50.0));
+
+        let bob = &scorecard.rows[0];
+        assert_eq!(bob.score(Metric::Resolved).unwrap().percentile, Some(100.0));
+        assert_eq!(bob.score(Metric::Resolved).unwrap().delta, None);
+
+        let cat = scorecard.rows.iter().find(|r| r.name == "Cat").unwrap();
+        assert_eq!(cat.score(Metric::ResponseTime).unwrap().value, None);
+        assert_eq!(cat.score(Metric::ResponseTime).unwrap().percentile, None);
+    }
+
+    #[test]
+    fn team_averages_and_percentiles() {
+        let scorecard = Scorecard::from_reports(team(), Metric::Resolved);
+        assert_eq!(scorecard.average(Metric::Resolved), Some(20.0));
+        assert_eq!(scorecard.average(Metric::ResponseTime), Some(450.0));
+        assert_eq!(scorecard.percentile(Metric::Resolved, 50.0), Some(20.0));
+        assert_eq!(scorecard.percentile(Metric::Resolved, 75.0), Some(25.0));
+        assert_eq!(scorecard.percentile(Metric::Resolved, 100.0), Some(30.0));
+
+        let empty = Scorecard::from_reports(Vec::new(), Metric::Resolved);
+        assert_eq!(empty.average(Metric::Resolved), None);
+        assert_eq!(empty.percentile(Metric::Resolved, 50.0), None);
+    }
+
+    #[test]
+    fn exports_ranked_table() {
+        let table = Scorecard::from_reports(team(), Metric::Resolved).to_table().unwrap();
+        assert_eq!(&table.columns()[..5], &["rank", "user.id", "user.name", "totalReplies", "totalReplies.previous"]);
+
+        let names = table.column("user.name").unwrap();
+        assert_eq!(names, vec![json!("Bob"), json!("Cat"), json!("Ann"), json!("team average")]);
+        assert_eq!(table.column("resolved").unwrap()[3], json!(20.0));
+        assert_eq!(table.column("resolved.delta").unwrap()[2], json!(100.0));
+    }
+}
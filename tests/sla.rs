@@ -0,0 +1,195 @@
+extern crate helpscout;
+extern crate chrono;
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod sla {
+    use chrono::prelude::*;
+    use chrono::Duration;
+    use serde_json::{self, Value};
+
+    use super::helpscout::{ConversationId, MailboxId};
+    use super::helpscout::api::conversations::Conversation;
+    use super::helpscout::calendar::BusinessCalendar;
+    use super::helpscout::sla::{Policy, Priority, SlaState, SlaTracker};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn person(id: i64, person_type: &str) -> Value {
+        json!({"id": id, "firstName": "Vernon", "lastName": "Bear", "email": "vbear@mywork.com", "type": person_type})
+    }
+
+    fn thread(id: i64, thread_type: &str, by_customer: bool, created_at: &str) -> Value {
+        json!({
+            "id": id,
+            "type": thread_type,
+            "status": "active",
+            "createdAt": created_at,
+            "createdBy": person(if by_customer { 29418 } else { 1234 }, if by_customer { "customer" } else { "user" }),
+            "state": "published",
+            "createdByCustomer": by_customer
+        })
+    }
+
+    fn conversation_json(status: &str, tags: Value, threads: Option<Value>) -> Value {
+        let mut conversation = json!({
+            "id": 2391938111i64,
+            "type": "email",
+            "folderId": 1234,
+            "isDraft": false,
+            "number": 349,
+            "owner": null,
+            "mailbox": {"id": 1234, "name": "My Mailbox"},
+            "customer": person(29418, "customer"),
+            "threadCount": 1,
+            "status": status,
+            "subject": "I need help!",
+            "createdBy": person(29418, "customer"),
+            // A Friday afternoon in New York
+            "createdAt": "2018-01-05T21:00:00Z",
+            "closedAt": null,
+            "source": {"type": "email", "via": "customer"},
+            "tags": tags
+        });
+        if let Some(threads) = threads {
+            conversation["threads"] = threads;
+        }
+        conversation
+    }
+
+    fn parse(value: Value) -> Conversation {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn office() -> BusinessCalendar {
+        BusinessCalendar::new(FixedOffset::west_opt(5 * 3600).unwrap())
+            .weekdays(NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap())
+            .holiday(NaiveDate::from_ymd_opt(2018, 1, 8).unwrap())
+    }
+
+    fn tracker() -> SlaTracker {
+        SlaTracker::new()
+            .priority_tag("vip", Priority::High)
+            .priority_tag("outage", Priority::Urgent)
+            .policy(Policy::new("Urgent").priority(Priority::Urgent).first_response(Duration::minutes(30)))
+            .policy(Policy::new("Support")
+                .mailbox(MailboxId(1234))
+                .first_response(Duration::hours(4))
+                .resolution(Duration::hours(16))
+                .warn_before(Duration::hours(1))
+                .business_hours(office()))
+    }
+
+    #[test]
+    fn priority_comes_from_tags() {
+        let tracker = tracker();
+        assert_eq!(tracker.priority(&parse(conversation_json("active", json!([]), None))), Priority::Normal);
+        assert_eq!(tracker.priority(&parse(conversation_json("active", json!(["vip", "outage"]), None))), Priority::Urgent);
+
+        let urgent = parse(conversation_json("active", json!(["outage"]), None));
+        assert_eq!(tracker.policy_for(&urgent).unwrap().name, "Urgent");
+        let normal = parse(conversation_json("active", json!(["vip"]), None));
+        assert_eq!(tracker.policy_for(&normal).unwrap().name, "Support");
+    }
+
+    #[test]
+    fn open_conversation_without_threads() {
+        let tracker = tracker();
+        let conversation = parse(conversation_json("active", json!([]), None));
+
+        let status = tracker.evaluate(&conversation, utc("2018-01-09T14:00:00Z")).unwrap();
+        assert_eq!(status.conversation, ConversationId(2391938111));
+        assert_eq!(status.policy, "Support");
+
+        let first = status.first_response.unwrap();
+        assert_eq!(first.state, SlaState::Unknown);
+        assert_eq!(first.due, Some(utc("2018-01-09T17:00:00Z")));
+
+        let resolution = status.resolution.unwrap();
+        assert_eq!(resolution.state, SlaState::OnTrack);
+        assert_eq!(resolution.remaining, Some(Duration::hours(15)));
+    }
+
+    #[test]
+    fn first_response_met_or_breached() {
+        let tracker = tracker();
+        let threads = json!([
+            thread(3, "message", false, "2018-01-09T16:00:00Z"),
+            thread(2, "note", false, "2018-01-09T14:30:00Z"),
+            thread(1, "customer", true, "2018-01-05T21:00:00Z"),
+        ]);
+        let conversation = parse(conversation_json("active", json!([]), Some(threads)));
+
+        let status = tracker.evaluate(&conversation, utc("2018-01-09T16:30:00Z")).unwrap();
+        let first = status.first_response.unwrap();
+        assert_eq!(first.state, SlaState::Met);
+        assert_eq!(first.completed_at, Some(utc("2018-01-09T16:00:00Z")));
+        assert_eq!(first.remaining, Some(Duration::hours(1)));
+
+        let late = json!([thread(2, "message", false, "2018-01-09T19:00:00Z"), thread(1, "customer", true, "2018-01-05T21:00:00Z")]);
+        let conversation = parse(conversation_json("active", json!([]), Some(late)));
+        let status = tracker.evaluate(&conversation, utc("2018-01-10T12:00:00Z")).unwrap();
+        assert!(status.breached());
+        assert_eq!(status.first_response.unwrap().remaining, Some(-Duration::hours(2)));
+    }
+
+    #[test]
+    fn at_risk_and_breached_while_open() {
+        let tracker = tracker();
+        let threads = json!([thread(1, "customer", true, "2018-01-05T21:00:00Z")]);
+        let conversation = parse(conversation_json("active", json!([]), Some(threads)));
+
+        let status = tracker.evaluate(&conversation, utc("2018-01-09T16:30:00Z")).unwrap();
+        assert!(status.at_risk());
+        assert!(!status.breached());
+        assert_eq!(status.next_due(), Some(utc("2018-01-09T17:00:00Z")));
+
+        let status = tracker.evaluate(&conversation, utc("2018-01-10T15:00:00Z")).unwrap();
+        assert!(status.breached());
+        assert_eq!(status.first_response.unwrap().remaining, Some(-Duration::hours(6)));
+    }
+
+    #[test]
+    fn closed_without_closed_at_is_unknown() {
+        let tracker = tracker();
+        let closed = parse(conversation_json("closed", json!([]), Some(json!([]))));
+        let status = tracker.evaluate(&closed, utc("2018-01-10T15:00:00Z")).unwrap();
+        assert_eq!(status.resolution.unwrap().state, SlaState::Unknown);
+
+        let mut closed = conversation_json("closed", json!([]), Some(json!([])));
+        closed["closedAt"] = json!("2018-01-03T15:00:00Z");
+        let status = tracker.evaluate(&parse(closed), utc("2018-01-10T15:00:00Z")).unwrap();
+        assert_eq!(status.resolution.unwrap().state, SlaState::Met);
+    }
+
+    #[test]
+    fn closed_calendar_never_falls_due() {
+        let closed = BusinessCalendar::new(Utc);
+        let tracker = SlaTracker::new().policy(Policy::new("Never").first_response(Duration::hours(1)).business_hours(closed));
+        let conversation = parse(conversation_json("active", json!([]), Some(json!([]))));
+
+        let first = tracker.evaluate(&conversation, utc("2018-01-10T15:00:00Z")).unwrap().first_response.unwrap();
+        assert_eq!(first.state, SlaState::Unknown);
+        assert_eq!(first.due, None);
+        assert_eq!(first.remaining, None);
+    }
+
+    #[test]
+    fn skips_spam_and_evaluates_webhooks() {
+        let tracker = tracker();
+        assert!(tracker.evaluate(&parse(conversation_json("spam", json!([]), None)), Utc::now()).is_none());
+
+        let mut closed = conversation_json("closed", json!(["outage"]), Some(json!([])));
+        closed["closedAt"] = json!("2018-01-05T21:10:00Z");
+        let status = tracker.evaluate_webhook(&closed.to_string(), utc("2018-01-06T00:00:00Z")).unwrap().unwrap();
+        assert_eq!(status.policy, "Urgent");
+        assert_eq!(status.priority, Priority::Urgent);
+        assert_eq!(status.first_response.unwrap().state, SlaState::Breached);
+        assert!(status.resolution.is_none());
+
+        assert!(tracker.evaluate_webhook("{}", Utc::now()).is_err());
+    }
+}
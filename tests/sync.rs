@@ -0,0 +1,119 @@
+#![cfg(feature = "sync")]
+extern crate helpscout;
+extern crate chrono;
+extern crate rusqlite;
+#[macro_use]
+extern crate serde_json;
+
+#[cfg(test)]
+mod sync {
+    use chrono::prelude::*;
+    use rusqlite::NO_PARAMS;
+    use serde_json::{self, Value};
+
+    use super::helpscout::{Collection, ConversationId};
+    use super::helpscout::api::conversations::Conversation;
+    use super::helpscout::sync::Mirror;
+
+    fn count(mirror: &Mirror, sql: &str) -> i64 {
+        mirror.connection().query_row(sql, NO_PARAMS, |row| row.get(0)).unwrap()
+    }
+
+    fn conversation_with_threads() -> Value {
+        let mut page: Value = serde_json::from_str(include_str!("fixtures/conversations.json")).unwrap();
+        let mut conversation = page["items"][0].take();
+        conversation["threads"] = json!([
+            {
+                "id": 88171991,
+                "type": "message",
+                "status": "active",
+                "createdAt": "2012-07-24T20:18:33Z",
+                "createdBy": {"id": 1234, "firstName": "Jack", "lastName": "Sprout", "email": "jack.sprout@gmail.com", "type": "user"},
+                "state": "published",
+                "body": "We're on it",
+                "createdByCustomer": false
+            },
+            {
+                "id": 88171990,
+                "type": "customer",
+                "status": "active",
+                "createdAt": "2012-07-23T12:34:12Z",
+                "createdBy": {"id": 29418, "firstName": "Vernon", "lastName": "Bear", "email": "vbear@mywork.com", "type": "customer"},
+                "body": "I need help!",
+                "createdByCustomer": true
+            }
+        ]);
+        conversation
+    }
+
+    #[test]
+    fn saves_conversations_tags_and_threads() {
+        let mirror = Mirror::open_in_memory().unwrap();
+        let page: Collection<Conversation> = serde_json::from_str(include_str!("fixtures/conversations.json")).unwrap();
+        for conversation in &page.items {
+            mirror.save_conversation(conversation).unwrap();
+        }
+        assert_eq!(count(&mirror, "SELECT count(*) FROM conversations"), 2);
+        assert_eq!(count(&mirror, "SELECT count(*) FROM conversation_tags WHERE conversation_id = 2391938111"), 2);
+        assert_eq!(count(&mirror, "SELECT count(*) FROM threads"), 1);
+
+        // Saving again replaces rather than duplicates, and threads come along when present
+        let conversation: Conversation = serde_json::from_value(conversation_with_threads()).unwrap();
+        mirror.save_conversation(&conversation).unwrap();
+        assert_eq!(count(&mirror, "SELECT count(*) FROM conversations"), 2);
+        assert_eq!(count(&mirror, "SELECT count(*) FROM threads WHERE conversation_id = 2391938111"), 2);
+        assert_eq!(count(&mirror, "SELECT count(*) FROM threads WHERE conversation_id = 2391938111 AND created_by_customer = 1"), 1);
+
+        let status: String = mirror.connection()
+            .query_row("SELECT status FROM conversations WHERE id = 2391938111", NO_PARAMS, |row| row.get(0))
+            .unwrap();
+        assert_eq!(status, "active");
+
+        mirror.delete_conversation(ConversationId(2391938111)).unwrap();
+        assert_eq!(count(&mirror, "SELECT count(*) FROM conversations"), 1);
+        assert_eq!(count(&mirror, "SELECT count(*) FROM threads WHERE conversation_id = 2391938111"), 0);
+        assert_eq!(count(&mirror, "SELECT count(*) FROM conversation_tags WHERE conversation_id = 2391938111"), 0);
+    }
+
+    #[test]
+    fn cursors_round_trip() {
+        let mirror = Mirror::open_in_memory().unwrap();
+        assert_eq!(mirror.cursor("customers").unwrap(), None);
+
+        let at = Utc.timestamp_opt(1514764800, 0).unwrap();
+        mirror.set_cursor("customers", at).unwrap();
+        mirror.set_cursor("conversations/1234", at).unwrap();
+        assert_eq!(mirror.cursor("customers").unwrap(), Some(at));
+
+        mirror.reset_cursors().unwrap();
+        assert_eq!(mirror.cursor("conversations/1234").unwrap(), None);
+    }
+
+    #[test]
+    fn applies_webhooks() {
+        let mut mirror = Mirror::open_in_memory().unwrap();
+
+        assert!(mirror.apply_webhook("convo.created", &conversation_with_threads().to_string()).unwrap());
+        assert_eq!(count(&mirror, "SELECT count(*) FROM threads"), 2);
+
+        let customer = json!({
+            "id": 29418,
+            "firstName": "Vernon",
+            "lastName": "Bear",
+            "gender": "male",
+            "createdAt": "2012-07-23T12:34:12Z",
+            "emails": [{"id": 1, "value": "vbear@mywork.com", "location": "work"}]
+        });
+        assert!(mirror.apply_webhook("customer.created", &customer.to_string()).unwrap());
+        assert_eq!(count(&mirror, "SELECT count(*) FROM customer_emails WHERE customer_id = 29418"), 1);
+
+        assert!(!mirror.apply_webhook("satisfaction.ratings", "{}").unwrap());
+
+        assert!(mirror.apply_webhook("convo.deleted", "{\"id\": 2391938111}").unwrap());
+        assert_eq!(count(&mirror, "SELECT count(*) FROM conversations"), 0);
+
+        // A bad body leaves the mirror alone
+        assert!(mirror.apply_webhook("convo.updated", "{\"id\": 1}").is_err());
+        assert_eq!(count(&mirror, "SELECT count(*) FROM customers"), 1);
+    }
+}
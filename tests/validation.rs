@@ -0,0 +1,53 @@
+extern crate helpscout;
+
+#[cfg(test)]
+mod validation {
+    use super::helpscout::MailboxId;
+    use super::helpscout::api::person::Person;
+    use super::helpscout::api::mailboxes::MailboxRef;
+    use super::helpscout::api::customers::{self, CustomerEmail, CustomerEmailLocationType, CustomerWebsite};
+    use super::helpscout::api::conversations::{ConversationThreadType, NewConversation, NewConversationThread};
+
+    #[test]
+    fn new_customer_is_valid() {
+        let email = CustomerEmail::new("jackie.chan@example.com", CustomerEmailLocationType::Work);
+        let customer = customers::create("Jackie", "Chan", vec![email]);
+        assert!(customer.validate().is_ok());
+    }
+
+    #[test]
+    fn new_customer_reports_every_problem() {
+        let email = CustomerEmail::new("not an email", CustomerEmailLocationType::Work);
+        let long_name: String = ::std::iter::repeat("a").take(41).collect();
+        let mut customer = customers::create(&long_name, "Chan", vec![email]);
+        customer.websites(vec![CustomerWebsite::new("example dot com")]);
+
+        let errors = customer.validate().expect_err("customer to be invalid");
+        let paths: Vec<&str> = errors.iter().map(|e| e.path.as_ref()).collect();
+        assert_eq!(paths, vec!["firstName", "emails[0].value", "websites[0].value"]);
+    }
+
+    #[test]
+    fn new_customer_needs_an_identifier() {
+        let customer = customers::create("", "", vec![]);
+        let errors = customer.validate().expect_err("customer to be invalid");
+        assert_eq!(errors[0].path, "customer");
+    }
+
+    #[test]
+    fn new_conversation_requires_a_thread() {
+        let mailbox = MailboxRef { id: MailboxId(1), name: "Support".into() };
+        let conversation = NewConversation::new(Person::new(1), "Help".into(), mailbox, vec![]);
+        let errors = conversation.validate().expect_err("conversation to be invalid");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "threads");
+    }
+
+    #[test]
+    fn new_conversation_is_valid() {
+        let mailbox = MailboxRef { id: MailboxId(1), name: "Support".into() };
+        let thread = NewConversationThread::new(ConversationThreadType::Customer, Person::new(2), "I need help".into());
+        let conversation = NewConversation::new(Person::new(1), "Help".into(), mailbox, vec![thread]);
+        assert!(conversation.validate().is_ok());
+    }
+}